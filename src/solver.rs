@@ -13,6 +13,28 @@ use itertools::Itertools;
 use logical_solver::adhoc_naked_set::AdhocNakedSet;
 use logical_solver::palindrome_candidates::PalindromeCandidates;
 use logical_solver::palindrome_values::PalindromeValues;
+use logical_solver::whispers_candidates::WhispersCandidates;
+use logical_solver::difference_line_candidates::DifferenceLineCandidates;
+use logical_solver::between_line_candidates::BetweenLineCandidates;
+use logical_solver::region_sum_line_candidates::RegionSumLineCandidates;
+use logical_solver::entropic_line_candidates::EntropicLineCandidates;
+use logical_solver::parity_line_candidates::ParityLineCandidates;
+use logical_solver::zipper_values::ZipperValues;
+use logical_solver::zipper_candidates::ZipperCandidates;
+use logical_solver::modular_line_candidates::ModularLineCandidates;
+use logical_solver::nabner_line_candidates::NabnerLineCandidates;
+use logical_solver::little_killer_candidates::LittleKillerCandidates;
+use logical_solver::sandwich_candidates::SandwichCandidates;
+use logical_solver::x_sum_candidates::XSumCandidates;
+use logical_solver::skyscraper_candidates::SkyscraperCandidates;
+use logical_solver::quadruple_candidates::QuadrupleCandidates;
+use logical_solver::non_consecutive_candidates::NonConsecutiveCandidates;
+use logical_solver::inequality_candidates::InequalityCandidates;
+use logical_solver::clone_values::CloneValues;
+use logical_solver::clone_candidates::CloneCandidates;
+use logical_solver::operator_cage_candidates::OperatorCageCandidates;
+use logical_solver::fortress_candidates::FortressCandidates;
+use logical_solver::min_max_candidates::MinMaxCandidates;
 use self::logical_solver::advanced_candidates::CellEliminationsResult;
 use self::logical_solver::arrow_candidates::ArrowCombinationLogicFactory;
 use self::logical_solver::candidates::Candidates;
@@ -22,6 +44,7 @@ use self::logical_solver::common_peer_elimination_kropki::CommonPeerEliminationK
 use self::logical_solver::hidden_set::HiddenSet;
 use self::logical_solver::hidden_singles::HiddenSingles;
 use self::logical_solver::killer45::Killer45;
+use self::logical_solver::killer45_product::Killer45Product;
 use self::logical_solver::killer_candidates::KillerCandidates;
 use self::logical_solver::kropki_chain_candidates::KropkiChainCandidates;
 use self::logical_solver::locked_candidates::LockedCandidates;
@@ -70,6 +93,13 @@ const ADJACENT_MOVES: [CellDirection; 4] = [
   CellDirection { row: -1, col: 0 },
 ];
 
+const DIAGONAL_MOVES: [CellDirection; 4] = [
+  CellDirection { row: -1, col: -1 },
+  CellDirection { row: -1, col: 1 },
+  CellDirection { row: 1, col: -1 },
+  CellDirection { row: 1, col: 1 },
+];
+
 pub struct Solver {
   pub constraints: NormalizedSudokuConstraints,
   pub techniques: Vec<Rc<dyn Technique>>,
@@ -81,7 +111,10 @@ pub struct Solver {
   grid_to_kropki_dots: Vec<Vec<Vec<usize>>>,
   grid_to_odd_cells: Vec<Vec<bool>>,
   grid_to_even_cells: Vec<Vec<bool>>,
+  grid_to_minimum_cells: Vec<Vec<bool>>,
+  grid_to_maximum_cells: Vec<Vec<bool>>,
   grid_to_renbans: Vec<Vec<Vec<usize>>>,
+  grid_to_nabner_lines: Vec<Vec<Vec<usize>>>,
   candidates_active: bool,
   candidates: Vec<Vec<HashSet<u32>>>,
   hint_mode: bool,
@@ -104,7 +137,10 @@ impl Clone for Solver {
       grid_to_kropki_dots: self.grid_to_kropki_dots.clone(),
       grid_to_odd_cells: self.grid_to_odd_cells.clone(),
       grid_to_even_cells: self.grid_to_even_cells.clone(),
+      grid_to_minimum_cells: self.grid_to_minimum_cells.clone(),
+      grid_to_maximum_cells: self.grid_to_maximum_cells.clone(),
       grid_to_renbans: self.grid_to_renbans.clone(),
+      grid_to_nabner_lines: self.grid_to_nabner_lines.clone(),
       candidates_active: self.candidates_active.clone(),
       candidates: self.candidates.clone(),
       hint_mode: self.hint_mode.clone(),
@@ -150,12 +186,23 @@ impl Solver {
       grid_to_kropki_dots[cell_1.row][cell_1.col].push(index);
       grid_to_kropki_dots[cell_2.row][cell_2.col].push(index);
     }
-    if constraints.kropki_negative {
+    let negative_dot_types = [
+      (constraints.kropki_negative, KropkiDotType::Negative),
+      (constraints.xv_negative, KropkiDotType::NegativeXV),
+    ];
+    for (negative, negative_dot_type) in negative_dot_types {
+      if !negative {
+        continue
+      }
       for row in 0..constraints.grid_size {
         for col in 0..constraints.grid_size {
           let cell = CellPosition::new(row, col);
           let adjacent_cells: HashSet<CellPosition> = Self::get_adjacent_cells(cell, constraints.grid_size).into_iter().collect();
+          // Only dots of the same family (kropki or XV) prevent the negative constraint
           let dot_cells: HashSet<CellPosition> = grid_to_kropki_dots[row][col].iter()
+            .filter(|&&kropki_dot_index| {
+              constraints.kropki_dots[kropki_dot_index].dot_type.negative_dot_type() == negative_dot_type
+            })
             .map(|&kropki_dot_index| {
               let kropki_dot = &constraints.kropki_dots[kropki_dot_index];
               let other_cell = kropki_dot.other_cell(&cell);
@@ -168,7 +215,7 @@ impl Solver {
             grid_to_kropki_dots[cell.row][cell.col].push(kropki_dot_index);
             grid_to_kropki_dots[negative_cell.row][negative_cell.col].push(kropki_dot_index);
             constraints.kropki_dots.push(KropkiDot {
-              dot_type: KropkiDotType::Negative,
+              dot_type: negative_dot_type.clone(),
               cell_1: cell,
               cell_2: negative_cell,
             })
@@ -187,6 +234,16 @@ impl Solver {
       grid_to_even_cells[cell.row][cell.col] = true;
     }
 
+    let mut grid_to_minimum_cells = vec![ vec![ false; constraints.grid_size ]; constraints.grid_size ];
+    for cell in &constraints.minimum_cells {
+      grid_to_minimum_cells[cell.row][cell.col] = true;
+    }
+
+    let mut grid_to_maximum_cells = vec![ vec![ false; constraints.grid_size ]; constraints.grid_size ];
+    for cell in &constraints.maximum_cells {
+      grid_to_maximum_cells[cell.row][cell.col] = true;
+    }
+
     let mut grid_to_renbans = vec![ vec![ vec![]; constraints.grid_size ]; constraints.grid_size ];
     for (index, renban) in constraints.renbans.iter().enumerate() {
       for cell in renban.iter() {
@@ -194,6 +251,13 @@ impl Solver {
       }
     }
 
+    let mut grid_to_nabner_lines = vec![ vec![ vec![]; constraints.grid_size ]; constraints.grid_size ];
+    for (index, nabner_line) in constraints.nabner_lines.iter().enumerate() {
+      for cell in nabner_line.iter() {
+        grid_to_nabner_lines[cell.row][cell.col].push(index);
+      }
+    }
+
     let grid = Self::build_initial_grid(&constraints);
     let candidates = Self::build_initial_candidates(&constraints);
 
@@ -207,7 +271,10 @@ impl Solver {
       grid_to_kropki_dots,
       grid_to_odd_cells,
       grid_to_even_cells,
+      grid_to_minimum_cells,
+      grid_to_maximum_cells,
       grid_to_renbans,
+      grid_to_nabner_lines,
       candidates_active: false,
       candidates,
       hint_mode: false,
@@ -283,11 +350,34 @@ impl Solver {
       Rc::new(RenbanCandidates),
       Rc::new(PalindromeValues),
       Rc::new(PalindromeCandidates),
+      Rc::new(WhispersCandidates),
+      Rc::new(DifferenceLineCandidates),
+      Rc::new(BetweenLineCandidates),
+      Rc::new(RegionSumLineCandidates),
+      Rc::new(EntropicLineCandidates),
+      Rc::new(ParityLineCandidates),
+      Rc::new(ZipperValues),
+      Rc::new(ZipperCandidates),
+      Rc::new(ModularLineCandidates),
+      Rc::new(NabnerLineCandidates),
+      Rc::new(LittleKillerCandidates),
+      Rc::new(SandwichCandidates),
+      Rc::new(XSumCandidates),
+      Rc::new(SkyscraperCandidates),
+      Rc::new(QuadrupleCandidates),
+      Rc::new(NonConsecutiveCandidates),
+      Rc::new(InequalityCandidates),
+      Rc::new(CloneValues),
+      Rc::new(CloneCandidates),
+      Rc::new(OperatorCageCandidates),
+      Rc::new(FortressCandidates),
+      Rc::new(MinMaxCandidates),
       Rc::new(NakedSingle),
       Rc::new(HiddenSingles),
       Rc::new(Thermo),
       Rc::new(Candidates),
       Rc::new(Killer45),
+      Rc::new(Killer45Product),
       Rc::new(LockedCandidates::new(2)),
       Rc::new(NakedSet::new(2)),
       Rc::new(HiddenSet::new(2)),
@@ -341,15 +431,28 @@ impl Solver {
       #[allow(unused_parens)]
       (
         &Area::Adhoc(_) | &Area::Row(_) | &Area::Column(_) | &Area::Region(_) | &Area::Renban(_) |
-        &Area::PrimaryDiagonal | &Area::SecondaryDiagonal
+        &Area::PrimaryDiagonal | &Area::SecondaryDiagonal | &Area::DisjointGroup(_)
       ) => self.compute_generic_area_cell_candidates(area),
-      &Area::Thermo(thermo_index) => self.compute_thermo_cell_candidates(thermo_index, cell),
+      &Area::Thermo(thermo_index) => {
+        self.compute_thermo_cell_candidates(&self.constraints.thermos[thermo_index], cell, false)
+      },
+      &Area::SlowThermo(thermo_index) => {
+        self.compute_thermo_cell_candidates(&self.constraints.slow_thermos[thermo_index], cell, true)
+      },
+      &Area::BranchingThermo(thermo_index) => self.compute_branching_thermo_cell_candidates(thermo_index, cell),
       &Area::KillerCage(killer_cage_index) => self.compute_killer_cell_candidates(killer_cage_index),
+      &Area::NabnerLine(_) => self.compute_nabner_line_cell_candidates(area),
       &Area::KropkiDot(_) => {
         // Do not enforce candidates directly, use an explicit rule for that
         self.compute_all_candidates()
       },
-      &Area::Grid | &Area::Cell(_, _) | &Area::Arrow(_) | &Area::Palindrome(_) => unimplemented!(),
+      &Area::Grid | &Area::Cell(_, _) | &Area::Arrow(_) | &Area::Palindrome(_) |
+        &Area::Whispers(_) | &Area::DifferenceLine(_) | &Area::BetweenLine(_) |
+        &Area::RegionSumLine(_) | &Area::EntropicLine(_) | &Area::ParityLine(_) |
+        &Area::Zipper(_) | &Area::ModularLine(_) | &Area::LittleKiller(_) |
+        &Area::Sandwich(_) | &Area::XSum(_) | &Area::Skyscraper(_) |
+        &Area::Quadruple(_) | &Area::Inequality(_) | &Area::CloneGroup(_) |
+        &Area::OperatorCage(_) | &Area::FortressCell(_) => unimplemented!(),
     }
   }
 
@@ -363,6 +466,18 @@ impl Solver {
     set
   }
 
+  fn compute_nabner_line_cell_candidates(&self, area: &Area) -> HashSet<u32> {
+    let mut set = self.compute_generic_area_cell_candidates(area);
+    for CellPosition { row, col } in self.get_area_cells(area) {
+      let value = self.grid[row][col];
+      if value != 0 {
+        set.remove(&(value - 1));
+        set.remove(&(value + 1));
+      }
+    }
+    set
+  }
+
   fn compute_killer_cell_candidates(&self, killer_cage_index: usize) -> HashSet<u32> {
     let area = Area::KillerCage(killer_cage_index);
     let mut set = self.compute_generic_area_cell_candidates(&area);
@@ -373,14 +488,16 @@ impl Solver {
         set.remove(&value);
       }
     }
+    if let Some(product) = killer_cage.product {
+      set.retain(|&value| product.is_multiple_of(value));
+    }
 
     set
   }
 
   // This could be made more intelligent, but we leave the tricks to logical_solver
-  fn compute_thermo_cell_candidates(&self, thermo_index: usize, area_cell: &CellPosition) -> HashSet<u32> {
-    let thermo = &self.constraints.thermos[thermo_index];
-
+  // Slow thermos allow equal values on adjacent cells
+  fn compute_thermo_cell_candidates(&self, thermo: &[CellPosition], area_cell: &CellPosition, slow: bool) -> HashSet<u32> {
     let mut after = false;
     let mut max_before = 0;
     let mut min_after = self.constraints.grid_size as u32 + 1;
@@ -402,8 +519,25 @@ impl Solver {
       }
     }
 
-    let set: HashSet<u32> = (max_before+1..=min_after-1).collect();
+    let set: HashSet<u32> = if slow {
+      (max(max_before, 1)..=min(min_after, self.constraints.grid_size as u32)).collect()
+    } else {
+      (max_before+1..=min_after-1).collect()
+    };
+
+    set
+  }
 
+  // The cell has to fit every path of the thermo that goes through it
+  fn compute_branching_thermo_cell_candidates(&self, thermo_index: usize, area_cell: &CellPosition) -> HashSet<u32> {
+    let mut set = self.compute_all_candidates();
+    for path in self.constraints.branching_thermos[thermo_index].paths() {
+      if !path.contains(area_cell) {
+        continue
+      }
+      let path_set = self.compute_thermo_cell_candidates(&path, area_cell, false);
+      set = set.intersection(&path_set).cloned().collect();
+    }
     set
   }
 
@@ -419,6 +553,16 @@ impl Solver {
     self.recompute_cell_candidates(cell)
   }
 
+  // The value for filled cells, the candidates otherwise
+  fn get_cell_possible_values(&self, cell: &CellPosition) -> HashSet<u32> {
+    let value = self.grid[cell.row][cell.col];
+    if value != 0 {
+      return HashSet::from([ value ])
+    }
+
+    self.candidates[cell.row][cell.col].clone()
+  }
+
   // Note: update when adding constraints
   // We don't apply all restrictions at this level (e.g. thermo, palindrome)
   fn recompute_cell_candidates(&self, cell: &CellPosition) -> HashSet<u32> {
@@ -431,6 +575,15 @@ impl Solver {
         candidates.remove(&value);
     }
 
+    for peer in self.get_non_consecutive_peers(cell) {
+      let value = self.grid[peer.row][peer.col];
+      if value == 0 {
+        continue
+      }
+      candidates.remove(&(value - 1));
+      candidates.remove(&(value + 1));
+    }
+
     for area in &self.get_cell_areas(cell, false) {
       let area_set = self.compute_area_cell_candidates(area, cell);
       candidates = candidates.intersection(&area_set).cloned().collect();
@@ -442,10 +595,55 @@ impl Solver {
     if self.grid_to_even_cells[cell.row][cell.col] {
       candidates = candidates.into_iter().filter(|value| value % 2 == 0).collect();
     }
+    if let Some(remainder) = self.compute_parity_line_cell_remainder(cell) {
+      candidates.retain(|value| value % 2 == remainder);
+    }
+
+    for peer in Self::get_adjacent_cells(*cell, self.constraints.grid_size) {
+      let value = self.grid[peer.row][peer.col];
+      if value == 0 {
+        continue
+      }
+      if self.grid_to_minimum_cells[cell.row][cell.col] || self.grid_to_maximum_cells[peer.row][peer.col] {
+        candidates.retain(|&candidate| candidate < value);
+      }
+      if self.grid_to_maximum_cells[cell.row][cell.col] || self.grid_to_minimum_cells[peer.row][peer.col] {
+        candidates.retain(|&candidate| candidate > value);
+      }
+    }
 
     candidates
   }
 
+  // The parity of a cell is known if it is filled or marked as odd or even
+  fn get_cell_known_remainder(&self, cell: &CellPosition) -> Option<u32> {
+    let value = self.grid[cell.row][cell.col];
+    if value != 0 {
+      return Some(value % 2)
+    }
+    if self.grid_to_odd_cells[cell.row][cell.col] {
+      return Some(1)
+    }
+    if self.grid_to_even_cells[cell.row][cell.col] {
+      return Some(0)
+    }
+    None
+  }
+
+  // Any cell with a known parity on a parity line determines the parity of the other cells
+  fn compute_parity_line_cell_remainder(&self, cell: &CellPosition) -> Option<u32> {
+    self.constraints.parity_lines.iter().find_map(|line| {
+      let cell_index = line.iter().position(|line_cell| line_cell == cell)?;
+      line.iter().enumerate().find_map(|(index, line_cell)| {
+        if index == cell_index {
+          return None
+        }
+        let remainder = self.get_cell_known_remainder(line_cell)?;
+        Some((remainder + (index + cell_index) as u32) % 2)
+      })
+    })
+  }
+
   fn compute_all_candidates(&self) -> HashSet<u32> {
     (1..=self.constraints.grid_size as u32).collect::<HashSet<u32>>()
   }
@@ -490,8 +688,11 @@ impl Solver {
     if self.constraints.secondary_diagonal && row == self.constraints.grid_size - 1 - col {
       areas.push(Area::SecondaryDiagonal);
     }
+    if self.constraints.disjoint_groups {
+      areas.push(Area::DisjointGroup(self.get_disjoint_group_index(cell)));
+    }
     let killer_cage_index = self.grid_to_killer_cage[row][col];
-    if killer_cage_index != usize::MAX {
+    if killer_cage_index != usize::MAX && self.constraints.killer_cages[killer_cage_index].unique {
       areas.push(Area::KillerCage(killer_cage_index));
     }
     if include_thermo {
@@ -502,6 +703,9 @@ impl Solver {
     for &renban_index in &self.grid_to_renbans[row][col] {
       areas.push(Area::Renban(renban_index));
     }
+    for &line_index in &self.grid_to_nabner_lines[row][col] {
+      areas.push(Area::NabnerLine(line_index));
+    }
 
     areas
   }
@@ -522,10 +726,21 @@ impl Solver {
     if self.constraints.secondary_diagonal {
       areas.push(Area::SecondaryDiagonal);
     }
+    if self.constraints.disjoint_groups {
+      for group_index in 0..self.constraints.grid_size {
+        areas.push(Area::DisjointGroup(group_index));
+      }
+    }
     if include_thermo {
       for thermo_index in 0..self.constraints.thermos.len() {
         areas.push(Area::Thermo(thermo_index));
       }
+      for thermo_index in 0..self.constraints.slow_thermos.len() {
+        areas.push(Area::SlowThermo(thermo_index));
+      }
+      for thermo_index in 0..self.constraints.branching_thermos.len() {
+        areas.push(Area::BranchingThermo(thermo_index));
+      }
     }
     if include_killer {
       for killer_cage_index in 0..self.constraints.killer_cages.len() {
@@ -578,6 +793,8 @@ impl Solver {
       &Area::Column(col) => self.get_col_cells(col),
       &Area::Region(region_index) => self.constraints.regions[region_index].to_vec(),
       &Area::Thermo(thermo_index) => self.constraints.thermos[thermo_index].to_vec(),
+      &Area::SlowThermo(thermo_index) => self.constraints.slow_thermos[thermo_index].to_vec(),
+      &Area::BranchingThermo(thermo_index) => self.constraints.branching_thermos[thermo_index].cells(),
       &Area::KillerCage(killer_cage_index) => {
         self.constraints.killer_cages[killer_cage_index].region.to_vec()
       },
@@ -587,8 +804,36 @@ impl Solver {
       },
       &Area::PrimaryDiagonal => self.get_primary_diagonal_cells(),
       &Area::SecondaryDiagonal => self.get_secondary_diagonal_cells(),
+      &Area::DisjointGroup(group_index) => self.get_disjoint_group_cells(group_index),
       &Area::Renban(renban_index) => self.constraints.renbans[renban_index].to_vec(),
       &Area::Palindrome(palindrome_index) => self.constraints.palindromes[palindrome_index].to_vec(),
+      &Area::Whispers(whispers_index) => self.constraints.whispers[whispers_index].to_vec(),
+      &Area::DifferenceLine(line_index) => self.constraints.difference_lines[line_index].cells.to_vec(),
+      &Area::BetweenLine(line_index) => self.constraints.between_lines[line_index].to_vec(),
+      &Area::RegionSumLine(line_index) => self.constraints.region_sum_lines[line_index].to_vec(),
+      &Area::EntropicLine(line_index) => self.constraints.entropic_lines[line_index].to_vec(),
+      &Area::ParityLine(line_index) => self.constraints.parity_lines[line_index].to_vec(),
+      &Area::Zipper(zipper_index) => self.constraints.zippers[zipper_index].to_vec(),
+      &Area::ModularLine(line_index) => self.constraints.modular_lines[line_index].to_vec(),
+      &Area::NabnerLine(line_index) => self.constraints.nabner_lines[line_index].to_vec(),
+      &Area::LittleKiller(little_killer_index) => {
+        self.constraints.little_killers[little_killer_index].cells(self.constraints.grid_size)
+      },
+      &Area::Sandwich(sandwich_index) => {
+        self.constraints.sandwiches[sandwich_index].cells(self.constraints.grid_size)
+      },
+      &Area::XSum(x_sum_index) => self.constraints.x_sums[x_sum_index].cells(self.constraints.grid_size),
+      &Area::Skyscraper(skyscraper_index) => {
+        self.constraints.skyscrapers[skyscraper_index].cells(self.constraints.grid_size)
+      },
+      &Area::Quadruple(quadruple_index) => self.constraints.quadruples[quadruple_index].cells(),
+      &Area::Inequality(inequality_index) => {
+        let inequality = &self.constraints.inequalities[inequality_index];
+        vec![ inequality.smaller_cell, inequality.larger_cell ]
+      },
+      &Area::CloneGroup(clone_group_index) => self.constraints.clones[clone_group_index].concat(),
+      &Area::OperatorCage(operator_cage_index) => self.constraints.operator_cages[operator_cage_index].region.to_vec(),
+      &Area::FortressCell(fortress_cell_index) => vec![ self.constraints.fortress_cells[fortress_cell_index] ],
       &Area::Arrow(_) => unimplemented!(),
     }
   }
@@ -642,6 +887,25 @@ impl Solver {
     }).collect()
   }
 
+  // Disjoint groups are indexed by the position of their cells within a box
+  fn get_disjoint_group_index(&self, cell: &CellPosition) -> usize {
+    let (region_height, region_width) = SudokuConstraints::compute_region_sizes(self.constraints.grid_size);
+    (cell.row % region_height) * region_width + cell.col % region_width
+  }
+
+  fn get_disjoint_group_cells(&self, group_index: usize) -> Vec<CellPosition> {
+    let grid_size = self.constraints.grid_size;
+    let (region_height, region_width) = SudokuConstraints::compute_region_sizes(grid_size);
+    let row_offset = group_index / region_width;
+    let col_offset = group_index % region_width;
+
+    (0..grid_size).step_by(region_height).flat_map(|region_row| {
+      (0..grid_size).step_by(region_width).map(move |region_col| {
+        CellPosition::new(region_row + row_offset, region_col + col_offset)
+      })
+    }).collect()
+  }
+
   #[allow(dead_code)]
   fn compute_area_candidates_union(&self, area: &Area) -> HashSet<u32> {
     let mut area_candidates: HashSet<u32> = HashSet::new();
@@ -732,9 +996,22 @@ impl Solver {
       peers.extend(self.get_king_peers(cell));
     }
 
+    if include_thermo {
+      peers.extend(self.get_branching_thermo_peers(cell));
+    }
+
     peers
   }
 
+  // Only cells on the same path are increasing, cells on different branches can repeat
+  fn get_branching_thermo_peers(&self, cell: &CellPosition) -> Vec<CellPosition> {
+    self.constraints.branching_thermos.iter()
+      .flat_map(|thermo| thermo.paths())
+      .filter(|path| path.contains(cell))
+      .flat_map(|path| path.to_vec())
+      .collect()
+  }
+
   // Returns peers that are special and are not peers through classical constraints
   fn get_cell_only_special_peers(&self, cell: &CellPosition, include_thermo: bool) -> Vec<CellPosition> {
     let special_peers = self.get_cell_special_peers(cell, include_thermo);
@@ -777,6 +1054,39 @@ impl Solver {
     }).collect()
   }
 
+  // Unshaded cells next to a fortress cell, they must all be smaller than it
+  fn get_fortress_neighbours(&self, fortress_cell: &CellPosition) -> Vec<CellPosition> {
+    Self::get_adjacent_cells(*fortress_cell, self.constraints.grid_size)
+      .into_iter()
+      .filter(|cell| !self.constraints.fortress_cells.contains(cell))
+      .collect()
+  }
+
+  // Cells that can't hold a value consecutive to <cell>'s value
+  fn get_non_consecutive_peers(&self, cell: &CellPosition) -> Vec<CellPosition> {
+    let mut directions: Vec<&CellDirection> = vec![];
+    if self.constraints.non_consecutive {
+      directions.extend(ADJACENT_MOVES.iter());
+    }
+    if self.constraints.diagonal_non_consecutive {
+      directions.extend(DIAGONAL_MOVES.iter());
+    }
+
+    directions.into_iter().filter_map(|direction| {
+      let prow = cell.row as isize + direction.row;
+      let pcol = cell.col as isize + direction.col;
+      if prow < 0 || prow >= self.constraints.grid_size as isize ||
+         pcol < 0 || pcol >= self.constraints.grid_size as isize {
+        return None
+      }
+      let peer = CellPosition {
+        row: prow as usize,
+        col: pcol as usize,
+      };
+      Some(peer)
+    }).collect()
+  }
+
   fn is_empty_area_subset(&self, small_area: &Area, big_area: &Area) -> bool {
     let small_set: HashSet<CellPosition> = self.get_empty_area_cells(small_area).into_iter().collect();
     if small_set.is_empty() {
@@ -799,6 +1109,21 @@ impl Solver {
     killer_cage.sum.unwrap() - killer_cells_sum
   }
 
+  fn get_subset_area_product(&self, killer_cage: &KillerCage, big_area: &Area) -> u32 {
+    let big_set: HashSet<CellPosition> = self.get_area_cells(big_area).into_iter().collect();
+    let killer_cells_product: u32 = killer_cage.region.iter().map(|&cell| {
+      if !big_set.contains(&cell) {
+        self.grid[cell.row][cell.col]
+      } else {
+        1
+      }
+    }).product();
+    if !killer_cage.product.unwrap().is_multiple_of(killer_cells_product) {
+      return 0
+    }
+    killer_cage.product.unwrap() / killer_cells_product
+  }
+
   #[allow(dead_code)]
   fn bit_mask_to_hash_set(&self, combination_mask: u32) -> HashSet<u32> {
     (1..=self.constraints.grid_size).filter_map(|value| {
@@ -839,6 +1164,18 @@ impl Solver {
     (sum, full)
   }
 
+  // Splits the line into consecutive runs of cells from the same region
+  fn get_region_sum_line_segments(&self, line_index: usize) -> Vec<Vec<CellPosition>> {
+    let line = &self.constraints.region_sum_lines[line_index];
+    let cell_region = |cell: &CellPosition| {
+      self.grid_to_regions[cell.row][cell.col]
+        .iter()
+        .find(|&&region_index| region_index < self.constraints.grid_size)
+        .copied()
+    };
+    line.chunk_by(|cell1, cell2| cell_region(cell1) == cell_region(cell2)).map(|segment| segment.to_vec()).collect()
+  }
+
   fn count_empty_cells_in_list(&self, cells: &Vec<CellPosition>) -> usize {
     cells.into_iter().filter(|cell| self.grid[cell.row][cell.col] == 0).count()
   }
@@ -1,5 +1,5 @@
 use crate::solver::Solver;
-use crate::types::{Area, CellPosition, InvalidStateReason, InvalidStateType, KropkiDot, KropkiDotType};
+use crate::types::{Area, CageOperator, CellPosition, InvalidStateReason, InvalidStateType, KropkiDot, KropkiDotType, Inequality, Whispers, EntropicLine, ModularLine};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
@@ -90,6 +90,132 @@ impl Solver {
       }
     }
 
+    for whispers_index in 0..self.constraints.whispers.len() {
+      let check = self.check_whispers_valid(whispers_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for line_index in 0..self.constraints.difference_lines.len() {
+      let check = self.check_difference_line_valid(line_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for line_index in 0..self.constraints.between_lines.len() {
+      let check = self.check_between_line_valid(line_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for line_index in 0..self.constraints.region_sum_lines.len() {
+      let check = self.check_region_sum_line_valid(line_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for line_index in 0..self.constraints.entropic_lines.len() {
+      let check = self.check_entropic_line_valid(line_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for line_index in 0..self.constraints.parity_lines.len() {
+      let check = self.check_parity_line_valid(line_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for zipper_index in 0..self.constraints.zippers.len() {
+      let check = self.check_zipper_valid(zipper_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for line_index in 0..self.constraints.modular_lines.len() {
+      let check = self.check_modular_line_valid(line_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for line_index in 0..self.constraints.nabner_lines.len() {
+      let check = self.check_nabner_line_valid(line_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for little_killer_index in 0..self.constraints.little_killers.len() {
+      let check = self.check_little_killer_valid(little_killer_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for sandwich_index in 0..self.constraints.sandwiches.len() {
+      let check = self.check_sandwich_valid(sandwich_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for x_sum_index in 0..self.constraints.x_sums.len() {
+      let check = self.check_x_sum_valid(x_sum_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for skyscraper_index in 0..self.constraints.skyscrapers.len() {
+      let check = self.check_skyscraper_valid(skyscraper_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for quadruple_index in 0..self.constraints.quadruples.len() {
+      let check = self.check_quadruple_valid(quadruple_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for inequality_index in 0..self.constraints.inequalities.len() {
+      let check = self.check_inequality_valid(inequality_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for clone_group_index in 0..self.constraints.clones.len() {
+      let check = self.check_clone_group_valid(clone_group_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for operator_cage_index in 0..self.constraints.operator_cages.len() {
+      let check = self.check_operator_cage_valid(operator_cage_index);
+      if !check.solved {
+        return check
+      }
+    }
+
+    for fortress_cell_index in 0..self.constraints.fortress_cells.len() {
+      let check = self.check_fortress_cell_valid(fortress_cell_index);
+      if !check.solved {
+        return check
+      }
+    }
+
     if self.constraints.anti_knight {
       let check = self.check_anti_knight_valid();
       if !check.solved {
@@ -104,6 +230,13 @@ impl Solver {
       }
     }
 
+    if self.constraints.non_consecutive || self.constraints.diagonal_non_consecutive {
+      let check = self.check_non_consecutive_valid();
+      if !check.solved {
+        return check
+      }
+    }
+
     let check = self.check_odd_cells();
     if !check.solved {
       return check
@@ -114,6 +247,16 @@ impl Solver {
       return check
     }
 
+    let check = self.check_minimum_cells();
+    if !check.solved {
+      return check
+    }
+
+    let check = self.check_maximum_cells();
+    if !check.solved {
+      return check
+    }
+
     if self.constraints.top_bottom {
       let check = self.check_top_bottom_valid();
       if !check.solved {
@@ -127,13 +270,24 @@ impl Solver {
   fn check_area_valid(&self, area: &Area) -> SolvedState {
     match area {
       &Area::Row(_) | &Area::Column(_) | &Area::Region(_) |
-        &Area::PrimaryDiagonal | &Area::SecondaryDiagonal => self.check_area_region_valid(area),
+        &Area::PrimaryDiagonal | &Area::SecondaryDiagonal |
+        &Area::DisjointGroup(_) => self.check_area_region_valid(area),
       &Area::KillerCage(killer_cage_index) => self.check_killer_area_valid(area, killer_cage_index),
-      &Area::Thermo(_) => self.check_thermo_area_valid(area),
+      &Area::Thermo(_) | &Area::SlowThermo(_) => {
+        let slow = matches!(area, Area::SlowThermo(_));
+        self.check_thermo_cells_valid(area, &self.get_area_cells(area), slow)
+      },
+      &Area::BranchingThermo(thermo_index) => self.check_branching_thermo_valid(area, thermo_index),
       &Area::KropkiDot(kropki_dot_index) => self.check_kropki_dot_valid(kropki_dot_index),
       &Area::Renban(_) => self.check_renban_valid(area),
       &Area::Palindrome(_) => self.check_palindrome_valid(area),
-      &Area::Grid | &Area::Adhoc(_) | &Area::Cell(_, _) | &Area::Arrow(_) => unimplemented!(),
+      &Area::Grid | &Area::Adhoc(_) | &Area::Cell(_, _) | &Area::Arrow(_) |
+        &Area::Whispers(_) | &Area::DifferenceLine(_) | &Area::BetweenLine(_) |
+        &Area::RegionSumLine(_) | &Area::EntropicLine(_) | &Area::ParityLine(_) |
+        &Area::Zipper(_) | &Area::ModularLine(_) | &Area::NabnerLine(_) |
+        &Area::LittleKiller(_) | &Area::Sandwich(_) | &Area::XSum(_) |
+        &Area::Skyscraper(_) | &Area::Quadruple(_) | &Area::Inequality(_) |
+        &Area::CloneGroup(_) | &Area::OperatorCage(_) | &Area::FortressCell(_) => unimplemented!(),
     }
   }
 
@@ -211,15 +365,26 @@ impl Solver {
     SolvedState::solved()
   }
 
-  fn check_thermo_area_valid(&self, area: &Area) -> SolvedState {
+  fn check_branching_thermo_valid(&self, area: &Area, thermo_index: usize) -> SolvedState {
+    for path in self.constraints.branching_thermos[thermo_index].paths() {
+      let check = self.check_thermo_cells_valid(area, &path, false);
+      if !check.solved {
+        return check
+      }
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_thermo_cells_valid(&self, area: &Area, cells: &[CellPosition], slow: bool) -> SolvedState {
     let mut crt_max_value: u32 = 0;
 
-    for CellPosition { row, col } in self.get_area_cells(area) {
+    for &CellPosition { row, col } in cells {
       let value = self.grid[row][col];
       if value == 0 {
         continue
       }
-      if value <= crt_max_value {
+      if value < crt_max_value || (value == crt_max_value && !slow) {
         return SolvedState::unsolved(
           InvalidStateReason {
             state_type: InvalidStateType::AreaConstraint,
@@ -331,6 +496,191 @@ impl Solver {
     SolvedState::solved()
   }
 
+  fn check_zipper_valid(&self, zipper_index: usize) -> SolvedState {
+    let area = Area::Zipper(zipper_index);
+    let zipper = &self.constraints.zippers[zipper_index];
+    let values = self.get_area_values(&area);
+
+    let mut sums: Vec<u32> = zipper.pairs().into_iter().filter_map(|(left, right)| {
+      if values[left] == 0 || values[right] == 0 {
+        return None
+      }
+      Some(values[left] + values[right])
+    }).collect();
+    if let Some(center) = zipper.center() {
+      if values[center] != 0 {
+        sums.push(values[center]);
+      }
+    }
+
+    let Some(&sum) = sums.first() else {
+      return SolvedState::solved()
+    };
+
+    // All sums must match and each remaining digit needs a positive partner
+    let invalid_value = sums.iter().copied().find(|&other_sum| other_sum != sum).or_else(|| {
+      zipper.pairs().into_iter().flat_map(|(left, right)| [ values[left], values[right] ])
+        .find(|&value| value >= sum)
+    });
+
+    if let Some(invalid_value) = invalid_value {
+      return SolvedState::unsolved(
+        InvalidStateReason {
+          state_type: InvalidStateType::AreaConstraint,
+          area,
+          values: vec![ sum, invalid_value ],
+        }
+      )
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_whispers_valid(&self, whispers_index: usize) -> SolvedState {
+    let min_difference = Whispers::min_difference(self.constraints.grid_size);
+    self.check_min_difference_line_valid(Area::Whispers(whispers_index), min_difference)
+  }
+
+  fn check_difference_line_valid(&self, line_index: usize) -> SolvedState {
+    let min_difference = self.constraints.difference_lines[line_index].min_difference;
+    self.check_min_difference_line_valid(Area::DifferenceLine(line_index), min_difference)
+  }
+
+  fn check_min_difference_line_valid(&self, area: Area, min_difference: u32) -> SolvedState {
+    let values = self.get_area_values(&area);
+
+    for &value in &values {
+      // There are no digits far enough from the middle ones
+      if value != 0 && values.len() > 1 &&
+         value <= min_difference && value + min_difference > self.constraints.grid_size as u32 {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area,
+            values: vec![value],
+          }
+        )
+      }
+    }
+
+    for pair in values.windows(2) {
+      if pair[0] != 0 && pair[1] != 0 && pair[0].abs_diff(pair[1]) < min_difference {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area,
+            values: pair.to_vec(),
+          }
+        )
+      }
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_between_line_valid(&self, line_index: usize) -> SolvedState {
+    let area = Area::BetweenLine(line_index);
+    let values = self.get_area_values(&area);
+    if values.len() < 2 {
+      return SolvedState::solved()
+    }
+
+    let first_value = values[0];
+    let last_value = values[values.len() - 1];
+    let inner_values: Vec<u32> = values[1..values.len() - 1].iter().copied().filter(|&value| value != 0).collect();
+
+    let invalid_state = |values: Vec<u32>| SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area,
+        values,
+      }
+    );
+
+    if first_value != 0 && last_value != 0 {
+      let lower_bound = first_value.min(last_value);
+      let upper_bound = first_value.max(last_value);
+      if first_value == last_value {
+        return invalid_state(vec![ first_value ])
+      }
+      if let Some(&value) = inner_values.iter().find(|&&value| value <= lower_bound || value >= upper_bound) {
+        return invalid_state(vec![ value ])
+      }
+      return SolvedState::solved()
+    }
+
+    // With a single circle filled in, the line digits must all be on the same side of it
+    let circle_value = first_value.max(last_value);
+    if circle_value != 0 {
+      if let Some(&value) = inner_values.iter().find(|&&value| value == circle_value) {
+        return invalid_state(vec![ value ])
+      }
+      let smaller_value = inner_values.iter().find(|&&value| value < circle_value);
+      let larger_value = inner_values.iter().find(|&&value| value > circle_value);
+      if let (Some(&smaller_value), Some(&larger_value)) = (smaller_value, larger_value) {
+        return invalid_state(vec![ smaller_value, larger_value ])
+      }
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_region_sum_line_valid(&self, line_index: usize) -> SolvedState {
+    let segment_sums: Vec<(u32, bool)> = self.get_region_sum_line_segments(line_index).iter().map(|segment| {
+      let sum: u32 = segment.iter().map(|cell| self.grid[cell.row][cell.col]).sum();
+      let full = segment.iter().all(|cell| self.grid[cell.row][cell.col] != 0);
+      (sum, full)
+    }).collect();
+
+    let Some(&(line_sum, _)) = segment_sums.iter().find(|(_, full)| *full) else {
+      return SolvedState::solved()
+    };
+
+    for &(sum, full) in &segment_sums {
+      if sum != line_sum && full || sum > line_sum {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area: Area::RegionSumLine(line_index),
+            values: vec![],
+          }
+        )
+      }
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_entropic_line_valid(&self, line_index: usize) -> SolvedState {
+    let grid_size = self.constraints.grid_size;
+    self.check_group_line_valid(Area::EntropicLine(line_index), |value| EntropicLine::value_group(value, grid_size))
+  }
+
+  fn check_modular_line_valid(&self, line_index: usize) -> SolvedState {
+    self.check_group_line_valid(Area::ModularLine(line_index), ModularLine::value_group)
+  }
+
+  fn check_group_line_valid(&self, area: Area, value_group: impl Fn(u32) -> u32) -> SolvedState {
+    let values = self.get_area_values(&area);
+
+    // Cells at most 2 apart are part of the same group of 3 consecutive cells
+    for (index, &value) in values.iter().enumerate() {
+      for &other_value in values.iter().skip(index + 1).take(2) {
+        if value != 0 && other_value != 0 && value_group(value) == value_group(other_value) {
+          return SolvedState::unsolved(
+            InvalidStateReason {
+              state_type: InvalidStateType::AreaConstraint,
+              area,
+              values: vec![ value, other_value ],
+            }
+          )
+        }
+      }
+    }
+
+    SolvedState::solved()
+  }
+
   fn check_anti_knight_valid(&self) -> SolvedState {
     for cell in self.get_area_cells(&Area::Grid) {
       let value = self.grid[cell.row][cell.col];
@@ -385,23 +735,55 @@ impl Solver {
     SolvedState::solved()
   }
 
+  fn check_non_consecutive_valid(&self) -> SolvedState {
+    for cell in self.get_area_cells(&Area::Grid) {
+      let value = self.grid[cell.row][cell.col];
+      if value == 0 {
+        continue
+      }
+
+      for peer in self.get_non_consecutive_peers(&cell) {
+        let peer_value = self.grid[peer.row][peer.col];
+        if peer_value == 0 {
+          continue
+        }
+        if value.abs_diff(peer_value) == 1 {
+          return SolvedState::unsolved(
+            InvalidStateReason {
+              state_type: InvalidStateType::CellInvalidValue,
+              area: Area::Cell(cell.row, cell.col),
+              values: vec![value],
+            }
+          )
+        }
+      }
+    }
+
+    SolvedState::solved()
+  }
+
   fn check_killer_area_valid(&self, area: &Area, killer_cage_index: usize) -> SolvedState {
-    let check = self.check_area_region_valid(area);
-    if !check.solved {
-      return check
+    let killer_cage = &self.constraints.killer_cages[killer_cage_index];
+    if killer_cage.unique {
+      let check = self.check_area_region_valid(area);
+      if !check.solved {
+        return check
+      }
     }
 
     let mut sum: u32 = 0;
+    let mut product: u32 = 1;
     let mut any_zero = false;
     for cell in self.get_area_cells(&area) {
       let value = self.grid[cell.row][cell.col];
       if value == 0 {
         any_zero = true;
+      } else {
+        product *= value;
       }
       sum += value;
     }
 
-    let killer_cage = &self.constraints.killer_cages[killer_cage_index];
     if let Some(killer_sum) = killer_cage.sum {
       if sum != killer_sum && !any_zero || sum > killer_sum {
         return SolvedState::unsolved(
@@ -413,6 +795,242 @@ impl Solver {
         )
       }
     }
+    if let Some(killer_product) = killer_cage.product {
+      if product != killer_product && !any_zero || killer_product % product != 0 {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area: area.clone(),
+            values: vec![],
+          }
+        )
+      }
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_little_killer_valid(&self, little_killer_index: usize) -> SolvedState {
+    let area = Area::LittleKiller(little_killer_index);
+    let values = self.get_area_values(&area);
+    let sum: u32 = values.iter().sum();
+    let any_zero = values.contains(&0);
+
+    let little_killer_sum = self.constraints.little_killers[little_killer_index].sum;
+    if sum != little_killer_sum && !any_zero || sum > little_killer_sum {
+      return SolvedState::unsolved(
+        InvalidStateReason {
+          state_type: InvalidStateType::AreaConstraint,
+          area,
+          values: vec![],
+        }
+      )
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_sandwich_valid(&self, sandwich_index: usize) -> SolvedState {
+    let area = Area::Sandwich(sandwich_index);
+    let values = self.get_area_values(&area);
+    let grid_size = self.constraints.grid_size as u32;
+
+    let crust_indices: Vec<usize> = values.iter().positions(|&value| value == 1 || value == grid_size).collect();
+    // The sum can't be checked until both crusts are placed
+    if crust_indices.len() < 2 {
+      return SolvedState::solved()
+    }
+
+    let inner_values = &values[crust_indices[0] + 1..crust_indices[1]];
+    let sum: u32 = inner_values.iter().sum();
+    let any_zero = inner_values.contains(&0);
+
+    let sandwich_sum = self.constraints.sandwiches[sandwich_index].value;
+    if sum != sandwich_sum && !any_zero || sum > sandwich_sum {
+      return SolvedState::unsolved(
+        InvalidStateReason {
+          state_type: InvalidStateType::AreaConstraint,
+          area,
+          values: vec![],
+        }
+      )
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_x_sum_valid(&self, x_sum_index: usize) -> SolvedState {
+    let area = Area::XSum(x_sum_index);
+    let values = self.get_area_values(&area);
+
+    // The first digit tells how many cells are summed
+    let count = values[0] as usize;
+    if count == 0 {
+      return SolvedState::solved()
+    }
+
+    let summed_values = &values[..count];
+    let sum: u32 = summed_values.iter().sum();
+    let any_zero = summed_values.contains(&0);
+
+    let x_sum = self.constraints.x_sums[x_sum_index].value;
+    if sum != x_sum && !any_zero || sum > x_sum {
+      return SolvedState::unsolved(
+        InvalidStateReason {
+          state_type: InvalidStateType::AreaConstraint,
+          area,
+          values: vec![],
+        }
+      )
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_skyscraper_valid(&self, skyscraper_index: usize) -> SolvedState {
+    let area = Area::Skyscraper(skyscraper_index);
+    let values = self.get_area_values(&area);
+
+    // Only the buildings before the first empty cell are surely visible
+    let mut visible_count = 0;
+    let mut max_value = 0;
+    for &value in values.iter().take_while(|&&value| value != 0) {
+      if value > max_value {
+        max_value = value;
+        visible_count += 1;
+      }
+    }
+
+    let skyscraper_count = self.constraints.skyscrapers[skyscraper_index].value;
+    let any_zero = values.contains(&0);
+    if visible_count != skyscraper_count && !any_zero || visible_count > skyscraper_count {
+      return SolvedState::unsolved(
+        InvalidStateReason {
+          state_type: InvalidStateType::AreaConstraint,
+          area,
+          values: vec![],
+        }
+      )
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_quadruple_valid(&self, quadruple_index: usize) -> SolvedState {
+    let area = Area::Quadruple(quadruple_index);
+    let mut values = self.get_area_values(&area);
+    let empty_count = values.iter().filter(|&&value| value == 0).count();
+
+    let mut missing_values = vec![];
+    for &quadruple_value in &self.constraints.quadruples[quadruple_index].values {
+      match values.iter().position(|&value| value == quadruple_value) {
+        // Each cell can satisfy only one of the listed values
+        Some(index) => { values.remove(index); },
+        None => missing_values.push(quadruple_value),
+      }
+    }
+
+    if missing_values.len() > empty_count {
+      return SolvedState::unsolved(
+        InvalidStateReason {
+          state_type: InvalidStateType::AreaConstraint,
+          area,
+          values: missing_values,
+        }
+      )
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_inequality_valid(&self, inequality_index: usize) -> SolvedState {
+    let Inequality { smaller_cell, larger_cell } = &self.constraints.inequalities[inequality_index];
+    let smaller_value = self.grid[smaller_cell.row][smaller_cell.col];
+    let larger_value = self.grid[larger_cell.row][larger_cell.col];
+    if smaller_value == 0 || larger_value == 0 || smaller_value < larger_value {
+      return SolvedState::solved()
+    }
+
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Inequality(inequality_index),
+        values: vec![],
+      }
+    )
+  }
+
+  fn check_clone_group_valid(&self, clone_group_index: usize) -> SolvedState {
+    for cells in self.constraints.clones[clone_group_index].corresponding_cells() {
+      let values: Vec<u32> = cells.iter()
+        .map(|cell| self.grid[cell.row][cell.col])
+        .filter(|&value| value != 0)
+        .unique()
+        .sorted()
+        .collect();
+
+      if values.len() > 1 {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area: Area::CloneGroup(clone_group_index),
+            values,
+          }
+        )
+      }
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_operator_cage_valid(&self, operator_cage_index: usize) -> SolvedState {
+    let operator_cage = &self.constraints.operator_cages[operator_cage_index];
+    let values: Vec<u32> = operator_cage.region.iter().map(|cell| self.grid[cell.row][cell.col]).collect();
+    let filled_values: Vec<u32> = values.iter().copied().filter(|&value| value != 0).collect();
+
+    let valid = if filled_values.len() == values.len() {
+      operator_cage.is_valid_for_values(&values)
+    } else {
+      match operator_cage.operator {
+        CageOperator::Add => filled_values.iter().sum::<u32>() < operator_cage.value,
+        CageOperator::Multiply => operator_cage.value.is_multiple_of(filled_values.iter().product::<u32>()),
+        // With only one of the 2 cells filled, the candidates of the other cell decide
+        CageOperator::Subtract | CageOperator::Divide => true,
+      }
+    };
+
+    if valid {
+      return SolvedState::solved()
+    }
+
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::OperatorCage(operator_cage_index),
+        values: vec![],
+      }
+    )
+  }
+
+  fn check_fortress_cell_valid(&self, fortress_cell_index: usize) -> SolvedState {
+    let fortress_cell = &self.constraints.fortress_cells[fortress_cell_index];
+    let fortress_value = self.grid[fortress_cell.row][fortress_cell.col];
+    if fortress_value == 0 {
+      return SolvedState::solved()
+    }
+
+    for cell in self.get_fortress_neighbours(fortress_cell) {
+      let value = self.grid[cell.row][cell.col];
+      if value >= fortress_value {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area: Area::FortressCell(fortress_cell_index),
+            values: vec![fortress_value, value],
+          }
+        )
+      }
+    }
 
     SolvedState::solved()
   }
@@ -439,6 +1057,15 @@ impl Solver {
       KropkiDotType::Negative => {
         value1 + 1 != value2 && value1 * 2 != value2
       },
+      KropkiDotType::X => {
+        value1 + value2 == 10
+      },
+      KropkiDotType::V => {
+        value1 + value2 == 5
+      },
+      KropkiDotType::NegativeXV => {
+        value1 + value2 != 10 && value1 + value2 != 5
+      },
     };
 
     if valid {
@@ -455,9 +1082,17 @@ impl Solver {
   }
 
   fn check_odd_cells(&self) -> SolvedState {
-    for cell in &self.constraints.odd_cells {
+    self.check_cells_parity(&self.constraints.odd_cells, 1)
+  }
+
+  fn check_even_cells(&self) -> SolvedState {
+    self.check_cells_parity(&self.constraints.even_cells, 0)
+  }
+
+  fn check_cells_parity(&self, cells: &[CellPosition], remainder: u32) -> SolvedState {
+    for cell in cells {
       let value = self.grid[cell.row][cell.col];
-      if value != 0 && value % 2 == 0 {
+      if value != 0 && value % 2 != remainder {
         return SolvedState::unsolved(
           InvalidStateReason {
             state_type: InvalidStateType::CellInvalidValue,
@@ -470,10 +1105,26 @@ impl Solver {
     SolvedState::solved()
   }
 
-  fn check_even_cells(&self) -> SolvedState {
-    for cell in &self.constraints.even_cells {
+  fn check_minimum_cells(&self) -> SolvedState {
+    self.check_cells_extremum(&self.constraints.minimum_cells, true)
+  }
+
+  fn check_maximum_cells(&self) -> SolvedState {
+    self.check_cells_extremum(&self.constraints.maximum_cells, false)
+  }
+
+  // Minimum cells must be smaller than all orthogonal neighbours, maximum cells larger
+  fn check_cells_extremum(&self, cells: &[CellPosition], minimum: bool) -> SolvedState {
+    for cell in cells {
       let value = self.grid[cell.row][cell.col];
-      if value != 0 && value % 2 == 1 {
+      if value == 0 {
+        continue
+      }
+      let invalid = Self::get_adjacent_cells(*cell, self.constraints.grid_size).into_iter().any(|peer| {
+        let peer_value = self.grid[peer.row][peer.col];
+        peer_value != 0 && if minimum { peer_value <= value } else { peer_value >= value }
+      });
+      if invalid {
         return SolvedState::unsolved(
           InvalidStateReason {
             state_type: InvalidStateType::CellInvalidValue,
@@ -486,6 +1137,67 @@ impl Solver {
     SolvedState::solved()
   }
 
+  fn check_nabner_line_valid(&self, line_index: usize) -> SolvedState {
+    let area = Area::NabnerLine(line_index);
+    let check = self.check_area_region_valid(&area);
+    if !check.solved {
+      return check
+    }
+
+    let values: Vec<u32> = self.get_area_values(&area).into_iter().filter(|&value| value != 0).collect();
+    for (value, other_value) in values.iter().tuple_combinations() {
+      if value.abs_diff(*other_value) == 1 {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area,
+            values: vec![ *value, *other_value ],
+          }
+        )
+      }
+    }
+
+    SolvedState::solved()
+  }
+
+  fn check_parity_line_valid(&self, line_index: usize) -> SolvedState {
+    let area = Area::ParityLine(line_index);
+    let values = self.get_area_values(&area);
+
+    for pair in values.windows(2) {
+      if pair[0] != 0 && pair[1] != 0 && pair[0] % 2 == pair[1] % 2 {
+        return SolvedState::unsolved(
+          InvalidStateReason {
+            state_type: InvalidStateType::AreaConstraint,
+            area,
+            values: pair.to_vec(),
+          }
+        )
+      }
+    }
+
+    // The first cell with a known parity determines the parity of all the other cells,
+    // even if there are empty cells between them
+    let line = &self.constraints.parity_lines[line_index];
+    let known_cell = line.iter().enumerate().find_map(|(index, cell)| {
+      self.get_cell_known_remainder(cell).map(|remainder| (index, remainder))
+    });
+    if let Some((known_index, remainder)) = known_cell {
+      for (offset, cells_remainder) in [ (0, remainder), (1, 1 - remainder) ] {
+        let cells: Vec<CellPosition> = line.iter().enumerate()
+          .filter(|(index, _)| (index + known_index) % 2 == offset)
+          .map(|(_, cell)| *cell)
+          .collect();
+        let check = self.check_cells_parity(&cells, cells_remainder);
+        if !check.solved {
+          return check
+        }
+      }
+    }
+
+    SolvedState::solved()
+  }
+
   fn check_top_bottom_valid(&self) -> SolvedState {
     let valid = TopBottomCandidates::new(true).run(&self).is_empty();
 
@@ -22,8 +22,11 @@ pub mod x_wing;
 pub mod xy_wing;
 pub mod common_peer_elimination;
 pub mod sum_candidates;
+pub mod product_candidates;
+pub mod operator_candidates;
 pub mod killer_candidates;
 pub mod killer45;
+pub mod killer45_product;
 pub mod kropki_chain_candidates;
 pub mod kropki_advanced_candidates;
 pub mod common_peer_elimination_kropki;
@@ -41,6 +44,28 @@ pub mod renban_candidates;
 pub mod palindrome_values;
 pub mod palindrome_candidates;
 pub mod adhoc_naked_set;
+pub mod whispers_candidates;
+pub mod difference_line_candidates;
+pub mod between_line_candidates;
+pub mod region_sum_line_candidates;
+pub mod entropic_line_candidates;
+pub mod parity_line_candidates;
+pub mod zipper_values;
+pub mod zipper_candidates;
+pub mod modular_line_candidates;
+pub mod nabner_line_candidates;
+pub mod little_killer_candidates;
+pub mod sandwich_candidates;
+pub mod x_sum_candidates;
+pub mod skyscraper_candidates;
+pub mod quadruple_candidates;
+pub mod non_consecutive_candidates;
+pub mod inequality_candidates;
+pub mod clone_values;
+pub mod clone_candidates;
+pub mod operator_cage_candidates;
+pub mod fortress_candidates;
+pub mod min_max_candidates;
 
 const DEBUG: bool = false;
 const DISPLAY_STEPS: bool = false;
@@ -335,7 +360,7 @@ impl Solver {
 
     if cells.iter().map(|cell| self.grid_to_killer_cage[cell.row][cell.col]).all_equal() {
       let killer_cage_index = self.grid_to_killer_cage[cell1.row][cell1.col];
-      if killer_cage_index != usize::MAX {
+      if killer_cage_index != usize::MAX && self.constraints.killer_cages[killer_cage_index].unique {
         areas.push(Area::KillerCage(killer_cage_index));
       }
     }
@@ -345,6 +370,9 @@ impl Solver {
     if self.constraints.secondary_diagonal && cells.iter().all(|cell| cell.row + cell.col == self.constraints.grid_size - 1) {
       areas.push(Area::SecondaryDiagonal);
     }
+    if self.constraints.disjoint_groups && cells.iter().map(|cell| self.get_disjoint_group_index(cell)).all_equal() {
+      areas.push(Area::DisjointGroup(self.get_disjoint_group_index(&cell1)));
+    }
 
     let mut common_renbans: HashSet<&usize> = self.grid_to_renbans[cells[0].row][cells[0].col].iter().collect();
     for cell in cells[1..].iter() {
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{SolutionStep, Rule, Area, BetweenLine};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because there is no valid pair of circle
+// values (lower end, upper end) such that all line digits fit strictly between them
+pub struct BetweenLineCandidates;
+
+impl Technique for BetweenLineCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::BetweenLineCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.between_lines.iter().enumerate().flat_map(|(line_index, line)| {
+      let valid_values = Self::find_valid_values(solver, line);
+
+      line.iter().zip(valid_values).filter_map(|(cell, cell_valid_values)| {
+        if solver.grid[cell.row][cell.col] != 0 {
+          return None
+        }
+
+        let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+          .difference(&cell_valid_values)
+          .copied()
+          .sorted()
+          .collect();
+
+        if invalid_values.is_empty() {
+          return None
+        }
+
+        Some(self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::BetweenLine(line_index) ],
+          vec![ *cell ],
+        ))
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl BetweenLineCandidates {
+  // Try each pair of circle values and keep the values that are consistent with at least one pair
+  fn find_valid_values(solver: &Solver, line: &BetweenLine) -> Vec<HashSet<u32>> {
+    let cell_values: Vec<HashSet<u32>> = line.iter().map(|cell| solver.get_cell_possible_values(cell)).collect();
+    if line.len() < 2 {
+      return cell_values
+    }
+
+    let mut valid_values: Vec<HashSet<u32>> = vec![ HashSet::new(); line.len() ];
+
+    let last_index = line.len() - 1;
+    let inner_values = &cell_values[1..last_index];
+
+    for &first_value in &cell_values[0] {
+      for &last_value in &cell_values[last_index] {
+        if first_value == last_value {
+          continue
+        }
+
+        let lower_bound = first_value.min(last_value);
+        let upper_bound = first_value.max(last_value);
+
+        let inner_valid_values: Vec<HashSet<u32>> = inner_values.iter().map(|values| {
+          values.iter().copied().filter(|&value| lower_bound < value && value < upper_bound).collect()
+        }).collect();
+
+        if inner_valid_values.iter().any(|values| values.is_empty()) {
+          continue
+        }
+
+        valid_values[0].insert(first_value);
+        valid_values[last_index].insert(last_value);
+        for (index, values) in inner_valid_values.into_iter().enumerate() {
+          valid_values[index + 1].extend(values);
+        }
+      }
+    }
+
+    valid_values
+  }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{Area, Rule, SolutionStep};
+use super::technique::Technique;
+
+// We must keep in sync candidates for corresponding cells in clones
+pub struct CloneCandidates;
+
+impl Technique for CloneCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+
+  fn get_rule(&self) -> Rule { Rule::CloneCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.clones.iter().enumerate().flat_map(|(clone_group_index, clone_group)| {
+      clone_group.corresponding_cells().into_iter().flat_map(|cells| {
+        // Filled cells are handled by CloneValues
+        if cells.iter().any(|cell| solver.grid[cell.row][cell.col] != 0) {
+          return vec![]
+        }
+
+        let common_candidates: HashSet<u32> = cells.iter()
+          .map(|cell| solver.candidates[cell.row][cell.col].clone())
+          .reduce(|acc, candidates| acc.intersection(&candidates).copied().collect())
+          .unwrap_or_default();
+
+        cells.into_iter().filter_map(|cell| {
+          let extra_candidates: Vec<u32> = solver.candidates[cell.row][cell.col]
+            .difference(&common_candidates)
+            .copied()
+            .sorted()
+            .collect();
+          if extra_candidates.is_empty() {
+            return None
+          }
+
+          Some(self.build_simple_solution_step(
+            extra_candidates,
+            vec![ Area::CloneGroup(clone_group_index) ],
+            vec![ cell ],
+          ))
+        }).collect()
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
@@ -0,0 +1,36 @@
+use crate::solver::Solver;
+use crate::types::{Area, Rule, SolutionStep};
+use super::technique::Technique;
+
+// We must put X in this cell because the corresponding cell in another clone has the same value
+pub struct CloneValues;
+
+impl Technique for CloneValues {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+
+  fn is_grid_step(&self) -> bool { true }
+
+  fn get_rule(&self) -> Rule { Rule::CloneValues }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    solver.constraints.clones.iter().enumerate().flat_map(|(clone_group_index, clone_group)| {
+      clone_group.corresponding_cells().into_iter().flat_map(|cells| {
+        let value = cells.iter()
+          .map(|cell| solver.grid[cell.row][cell.col])
+          .find(|&value| value != 0);
+        let Some(value) = value else {
+          return vec![]
+        };
+
+        cells.into_iter().filter(|cell| solver.grid[cell.row][cell.col] == 0).map(|cell| {
+          self.build_grid_solution_step(
+            vec![ cell ],
+            vec![ value ],
+            vec![ Area::CloneGroup(clone_group_index) ],
+            solver,
+          )
+        }).collect()
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
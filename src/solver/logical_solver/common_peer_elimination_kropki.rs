@@ -21,7 +21,7 @@ impl Technique for CommonPeerEliminationKropki {
     let mut steps: Vec<SolutionStep> = vec![];
 
     for area in &solver.get_all_proper_areas() {
-      let dot_types = vec![ KropkiDotType::Consecutive, KropkiDotType::Double ];
+      let dot_types = vec![ KropkiDotType::Consecutive, KropkiDotType::Double, KropkiDotType::X, KropkiDotType::V ];
       for dot_type in dot_types {
         let kropki_ccs = KropkiChainCandidates::compute_area_kropki_ccs(solver, area, dot_type, false);
         for (cells, kropki_dot_indices) in kropki_ccs {
@@ -0,0 +1,99 @@
+use std::cmp::min;
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::solver::logical_solver::combinations::cell_combinations_runner::CellCombinationsRunner;
+use crate::types::{Area, CellPosition, Rule, SolutionStep};
+use super::combinations::cell_combination_logic::CellCombinationLogic;
+use super::technique::Technique;
+
+const SEGMENT_LENGTH: usize = 5;
+
+// X can't be a candidate in this cell because adjacent digits on the
+// line must differ by at least the line's minimum difference
+pub struct DifferenceLineCandidates;
+
+impl Technique for DifferenceLineCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::DifferenceLineCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.difference_lines.iter().enumerate().flat_map(|(line_index, line)| {
+      let invalid_candidates = solver.detect_invalid_difference_line_candidates(&line.cells, line.min_difference);
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::DifferenceLine(line_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl Solver {
+  pub fn detect_invalid_difference_line_candidates(&self, cells: &[CellPosition], min_difference: u32) -> Vec<(CellPosition, Vec<u32>)> {
+    if cells.len() < 2 {
+      return vec![]
+    }
+
+    // Running the algorithm for a whole long line with a small difference takes too much time,
+    // so we check overlapping segments instead. Repeated runs spread eliminations along the line.
+    let segment_length = min(cells.len(), SEGMENT_LENGTH);
+
+    let mut invalid_values_by_cell: HashMap<CellPosition, HashSet<u32>> = HashMap::new();
+    for segment in cells.windows(segment_length) {
+      let combination_logic = DifferenceLineCombinationLogic::new(segment, min_difference);
+      let mut runner = CellCombinationsRunner::new(self, Box::new(combination_logic));
+      let (valid_candidates, _) = runner.run();
+
+      for (cell, invalid_values) in self.cell_candidates_diff(&segment.to_vec(), valid_candidates) {
+        invalid_values_by_cell.entry(cell).or_default().extend(invalid_values);
+      }
+    }
+
+    cells.iter().unique().filter_map(|cell| {
+      let invalid_values = invalid_values_by_cell.remove(cell)?;
+      Some((*cell, invalid_values.into_iter().sorted().collect()))
+    }).collect()
+  }
+}
+
+struct DifferenceLineCombinationLogic<'a> {
+  cells: &'a [CellPosition],
+  min_difference: u32,
+}
+
+impl<'a> DifferenceLineCombinationLogic<'a> {
+  fn new(cells: &'a [CellPosition], min_difference: u32) -> DifferenceLineCombinationLogic<'a> {
+    DifferenceLineCombinationLogic {
+      cells,
+      min_difference,
+    }
+  }
+}
+
+impl CellCombinationLogic for DifferenceLineCombinationLogic<'_> {
+  fn cells(&self) -> Vec<CellPosition> {
+    self.cells.to_vec()
+  }
+
+  fn is_value_valid_candidate_in_cell(&self, runner: &CellCombinationsRunner, value: u32, index: usize) -> bool {
+    if index == 0 {
+      return true
+    }
+
+    // Only the previous cell on the line is a neighbour that was already placed
+    let prev_value = runner.state.used_candidates[index - 1];
+
+    value.abs_diff(prev_value) >= self.min_difference
+  }
+
+  // Digits can repeat on the line, but only in cells that don't see each other
+  fn should_check_value_conflict(&self) -> bool { true }
+}
@@ -0,0 +1,88 @@
+use std::cmp::min;
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{Area, CellPosition, EntropicLine, Rule, SolutionStep};
+use super::technique::Technique;
+
+// Cells that are 3 apart on the line must use the same digit group (low, middle, high)
+// and each group of 3 consecutive cells uses all 3 groups.
+// X can't be a candidate in this cell because its group can't be assigned to the cell's position.
+pub struct EntropicLineCandidates;
+
+impl Technique for EntropicLineCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::EntropicLineCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    let grid_size = solver.constraints.grid_size;
+
+    solver.constraints.entropic_lines.iter().enumerate().flat_map(|(line_index, line)| {
+      let invalid_candidates = solver.detect_invalid_group_line_candidates(line, |value| EntropicLine::value_group(value, grid_size));
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::EntropicLine(line_index) ],
+          vec![ cell ],
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl Solver {
+  // Also used by modular lines, which only differ in how digits are grouped
+  pub fn detect_invalid_group_line_candidates(&self, cells: &[CellPosition], value_group: impl Fn(u32) -> u32) -> Vec<(CellPosition, Vec<u32>)> {
+    let valid_groups = self.find_valid_position_groups(cells, &value_group);
+
+    cells.iter().enumerate().filter_map(|(cell_index, cell)| {
+      if self.grid[cell.row][cell.col] != 0 {
+        return None
+      }
+
+      let invalid_values: Vec<u32> = self.candidates[cell.row][cell.col]
+        .iter()
+        .filter(|&&value| !valid_groups[cell_index % 3].contains(&value_group(value)))
+        .copied()
+        .sorted()
+        .collect();
+
+      if invalid_values.is_empty() {
+        return None
+      }
+
+      Some((*cell, invalid_values))
+    }).collect()
+  }
+
+  // Tries every assignment of distinct groups to the 3 position classes of the line
+  fn find_valid_position_groups(&self, cells: &[CellPosition], value_group: &impl Fn(u32) -> u32) -> Vec<HashSet<u32>> {
+    let cell_groups: Vec<HashSet<u32>> = cells.iter().map(|cell| {
+      self.get_cell_possible_values(cell)
+        .into_iter()
+        .map(value_group)
+        .collect()
+    }).collect();
+
+    let mut valid_groups: Vec<HashSet<u32>> = vec![ HashSet::new(); 3 ];
+    for position_groups in (0..3).permutations(min(cells.len(), 3)) {
+      let valid = cell_groups.iter().enumerate().all(|(cell_index, groups)| {
+        groups.contains(&position_groups[cell_index % 3])
+      });
+      if !valid {
+        continue
+      }
+
+      for (position, group) in position_groups.into_iter().enumerate() {
+        valid_groups[position].insert(group);
+      }
+    }
+
+    valid_groups
+  }
+}
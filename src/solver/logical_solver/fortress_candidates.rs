@@ -0,0 +1,66 @@
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{SolutionStep, Rule, Area, CellPosition};
+use super::technique::Technique;
+
+// A fortress cell must be larger than the smallest value of each unshaded neighbour
+// and each unshaded neighbour must be smaller than the largest value of the fortress cell
+pub struct FortressCandidates;
+
+impl Technique for FortressCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::FortressCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    for (fortress_cell_index, fortress_cell) in solver.constraints.fortress_cells.iter().enumerate() {
+      let neighbours = solver.get_fortress_neighbours(fortress_cell);
+      if neighbours.is_empty() {
+        continue
+      }
+
+      let lower_bound = neighbours.iter().map(|cell| {
+        solver.get_cell_possible_values(cell).into_iter().min().unwrap_or(0) + 1
+      }).max().unwrap();
+      let upper_bound = solver.get_cell_possible_values(fortress_cell)
+        .into_iter()
+        .max()
+        .unwrap_or(1) - 1;
+
+      let mut cell_bounds: Vec<(CellPosition, u32, u32)> = vec![ (*fortress_cell, lower_bound, u32::MAX) ];
+      cell_bounds.extend(neighbours.into_iter().map(|cell| (cell, 0, upper_bound)));
+
+      let steps: Vec<SolutionStep> = cell_bounds.into_iter().filter_map(|(cell, min_value, max_value)| {
+        if solver.grid[cell.row][cell.col] != 0 {
+          return None
+        }
+
+        let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+          .iter()
+          .filter(|&&value| value < min_value || value > max_value)
+          .copied()
+          .sorted()
+          .collect();
+
+        if invalid_values.is_empty() {
+          return None
+        }
+
+        Some(self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::FortressCell(fortress_cell_index) ],
+          vec![ cell ],
+        ))
+      }).collect();
+
+      if !steps.is_empty() {
+        return steps
+      }
+    }
+
+    vec![]
+  }
+}
@@ -0,0 +1,157 @@
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{SolutionStep, Rule, Area, CellPosition};
+use super::technique::Technique;
+
+// Each cell must be larger than every cell it points away from and smaller than every
+// cell pointing towards it. Bounds propagate along chains of connected signs.
+pub struct InequalityCandidates;
+
+impl Technique for InequalityCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::InequalityCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    for inequality_indices in Self::find_connected_inequalities(solver) {
+      let lower_bounds = Self::find_lower_bounds(solver, &inequality_indices);
+      let upper_bounds = Self::find_upper_bounds(solver, &inequality_indices);
+
+      let areas: Vec<Area> = inequality_indices.iter().map(|&index| Area::Inequality(index)).collect();
+
+      let steps: Vec<SolutionStep> = lower_bounds.keys().sorted().filter_map(|cell| {
+        if solver.grid[cell.row][cell.col] != 0 {
+          return None
+        }
+
+        let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+          .iter()
+          .filter(|&&value| value < lower_bounds[cell] || value > upper_bounds[cell])
+          .copied()
+          .sorted()
+          .collect();
+
+        if invalid_values.is_empty() {
+          return None
+        }
+
+        Some(self.build_simple_solution_step(
+          invalid_values,
+          areas.clone(),
+          vec![ *cell ],
+        ))
+      }).collect();
+
+      if !steps.is_empty() {
+        return steps
+      }
+    }
+
+    vec![]
+  }
+}
+
+impl InequalityCandidates {
+  // Groups inequalities that share cells, directly or through other inequalities
+  fn find_connected_inequalities(solver: &Solver) -> Vec<Vec<usize>> {
+    let inequalities = &solver.constraints.inequalities;
+    let mut group_indices: Vec<usize> = (0..inequalities.len()).collect();
+
+    for index in 0..inequalities.len() {
+      for other_index in 0..index {
+        let inequality = &inequalities[index];
+        let other_inequality = &inequalities[other_index];
+        let connected = [ inequality.smaller_cell, inequality.larger_cell ].iter().any(|cell| {
+          *cell == other_inequality.smaller_cell || *cell == other_inequality.larger_cell
+        });
+        if !connected {
+          continue
+        }
+
+        let old_group = group_indices[index];
+        let new_group = group_indices[other_index];
+        for group_index in group_indices.iter_mut() {
+          if *group_index == old_group {
+            *group_index = new_group;
+          }
+        }
+      }
+    }
+
+    (0..inequalities.len())
+      .into_group_map_by(|&index| group_indices[index])
+      .into_iter()
+      .sorted()
+      .map(|(_, indices)| indices)
+      .collect()
+  }
+
+  fn find_lower_bounds(solver: &Solver, inequality_indices: &[usize]) -> HashMap<CellPosition, u32> {
+    let max_value = solver.constraints.grid_size as u32 + 1;
+    let mut bounds = Self::initial_bounds(solver, inequality_indices, |values| values.iter().copied().min().unwrap_or(max_value));
+
+    // Each pass pushes bounds at least one sign further along the chains
+    let mut changed = true;
+    while changed {
+      changed = false;
+      for &inequality_index in inequality_indices {
+        let inequality = &solver.constraints.inequalities[inequality_index];
+        let smaller_bound = bounds[&inequality.smaller_cell];
+        let larger_bound = bounds[&inequality.larger_cell];
+
+        // If it reaches grid_size + 1 it should result in a cell with no candidates
+        let new_bound = solver.get_cell_possible_values(&inequality.larger_cell)
+          .into_iter()
+          .filter(|&value| value > smaller_bound)
+          .min()
+          .unwrap_or(max_value);
+        if new_bound > larger_bound {
+          bounds.insert(inequality.larger_cell, new_bound);
+          changed = true;
+        }
+      }
+    }
+
+    bounds
+  }
+
+  fn find_upper_bounds(solver: &Solver, inequality_indices: &[usize]) -> HashMap<CellPosition, u32> {
+    let mut bounds = Self::initial_bounds(solver, inequality_indices, |values| values.iter().copied().max().unwrap_or(0));
+
+    let mut changed = true;
+    while changed {
+      changed = false;
+      for &inequality_index in inequality_indices {
+        let inequality = &solver.constraints.inequalities[inequality_index];
+        let smaller_bound = bounds[&inequality.smaller_cell];
+        let larger_bound = bounds[&inequality.larger_cell];
+
+        // If it reaches 0 it should result in a cell with no candidates
+        let new_bound = solver.get_cell_possible_values(&inequality.smaller_cell)
+          .into_iter()
+          .filter(|&value| value < larger_bound)
+          .max()
+          .unwrap_or(0);
+        if new_bound < smaller_bound {
+          bounds.insert(inequality.smaller_cell, new_bound);
+          changed = true;
+        }
+      }
+    }
+
+    bounds
+  }
+
+  fn initial_bounds(
+    solver: &Solver, inequality_indices: &[usize], bound: impl Fn(&HashSet<u32>) -> u32,
+  ) -> HashMap<CellPosition, u32> {
+    inequality_indices.iter().flat_map(|&inequality_index| {
+      let inequality = &solver.constraints.inequalities[inequality_index];
+      [ inequality.smaller_cell, inequality.larger_cell ]
+    }).map(|cell| (cell, bound(&solver.get_cell_possible_values(&cell)))).collect()
+  }
+}
@@ -0,0 +1,84 @@
+use crate::solver::Solver;
+use crate::types::{SolutionStep, CellPosition, Rule, Area};
+use itertools::Itertools;
+use std::collections::HashSet;
+use super::technique::Technique;
+
+// With more cells left, the remaining product allows almost every digit, so the
+// eliminations rarely pay for enumerating the combinations
+const MAX_CELLS_LEFT: usize = 3;
+
+// Area A's cells must multiply to 9! and after dividing by product cages there is Y left.
+pub struct Killer45Product;
+
+impl Technique for Killer45Product {
+  fn get_rule(&self) -> Rule { Rule::Killer45Product }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+    if solver.constraints.killer_cages.iter().all(|killer_cage| killer_cage.product.is_none()) {
+      return vec![]
+    }
+
+    for area in &solver.get_all_proper_areas() {
+      let steps = self.find_killer45_product_in_area(solver, area);
+      if !steps.is_empty() {
+        return steps
+      }
+    }
+
+    vec![]
+  }
+}
+
+impl Killer45Product {
+  // Note: we assume there are no overlapping cages
+  fn find_killer45_product_in_area(&self, solver: &Solver, area: &Area) -> Vec<SolutionStep> {
+    let mut area_cells_set: HashSet<CellPosition> = solver.get_area_cells(area).into_iter().collect();
+    let mut region_product_left: u32 = (1..=solver.constraints.grid_size as u32).product();
+
+    for (killer_cage_index, killer_cage) in solver.constraints.killer_cages.iter().enumerate() {
+      if killer_cage.product.is_some() && solver.is_empty_area_subset(&Area::KillerCage(killer_cage_index), area) {
+        let subset_area_product = solver.get_subset_area_product(killer_cage, area);
+        if subset_area_product == 0 || !region_product_left.is_multiple_of(subset_area_product) {
+          return vec![]
+        }
+        region_product_left /= subset_area_product;
+        let killer_cage_set: HashSet<CellPosition> = killer_cage.region.iter().copied().collect();
+        area_cells_set = area_cells_set.difference(&killer_cage_set).copied().collect();
+      }
+    }
+
+    // Mark fixed digits
+    for cell in &area_cells_set.iter().copied().collect_vec() {
+      let value = solver.grid[cell.row][cell.col];
+      if value != 0 && area_cells_set.remove(cell) {
+        if !region_product_left.is_multiple_of(value) {
+          return vec![]
+        }
+        region_product_left /= value;
+      }
+    }
+
+    if area_cells_set.is_empty() || area_cells_set.len() > MAX_CELLS_LEFT {
+      return vec![]
+    }
+
+    let empty_cells = area_cells_set.iter().sorted().copied().collect();
+    let invalid_product_candidates = solver.detect_invalid_product_candidates(&empty_cells, region_product_left);
+
+    if invalid_product_candidates.is_empty() {
+      return vec![]
+    }
+
+    invalid_product_candidates.into_iter().map(|(cell, invalid_values)| {
+      self.build_simple_solution_step(
+        invalid_values,
+        vec![ area.clone() ],
+        vec![ cell ],
+      )
+    }).collect()
+  }
+}
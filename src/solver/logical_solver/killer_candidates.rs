@@ -4,7 +4,7 @@ use crate::types::{SolutionStep, CellPosition, Rule, Area};
 use super::technique::Technique;
 
 // X can't be a candidate in this cell because the other empty cells
-// can't be assigned to make the sum Y
+// can't be assigned to make the sum (or product) Y
 pub struct KillerCandidates;
 
 impl Technique for KillerCandidates {
@@ -17,7 +17,7 @@ impl Technique for KillerCandidates {
     }
 
     for (killer_cage_index, killer_cage) in solver.constraints.killer_cages.iter().enumerate() {
-      if killer_cage.sum.is_none() {
+      if killer_cage.sum.is_none() && killer_cage.product.is_none() {
         continue
       }
       let empty_cells: Vec<CellPosition> = killer_cage.region
@@ -29,17 +29,42 @@ impl Technique for KillerCandidates {
         continue
       }
 
-      let sum: u32 = killer_cage.region.iter().map(|cell| solver.grid[cell.row][cell.col]).sum();
-      let total_sum = killer_cage.sum.unwrap();
-      let rest_sum: u32 = total_sum - sum;
+      let mut invalid_candidates = vec![];
 
-      let invalid_sum_candidates = solver.detect_invalid_sum_candidates(&empty_cells, rest_sum);
+      if let Some(total_sum) = killer_cage.sum {
+        let sum: u32 = killer_cage.region.iter().map(|cell| solver.grid[cell.row][cell.col]).sum();
+        let rest_sum: u32 = total_sum - sum;
 
-      if invalid_sum_candidates.is_empty() {
+        invalid_candidates = if killer_cage.unique {
+          solver.detect_invalid_sum_candidates(&empty_cells, rest_sum)
+        } else {
+          solver.detect_invalid_sum_candidates_with_repeats(&empty_cells, rest_sum)
+        };
+      }
+
+      if invalid_candidates.is_empty() {
+        if let Some(total_product) = killer_cage.product {
+          let product: u32 = killer_cage.region
+            .iter()
+            .map(|cell| solver.grid[cell.row][cell.col])
+            .filter(|&value| value != 0)
+            .product();
+          // A product of 0 can't be made, so all candidates get removed
+          let rest_product: u32 = if total_product.is_multiple_of(product) { total_product / product } else { 0 };
+
+          invalid_candidates = if killer_cage.unique {
+            solver.detect_invalid_product_candidates(&empty_cells, rest_product)
+          } else {
+            solver.detect_invalid_product_candidates_with_repeats(&empty_cells, rest_product)
+          };
+        }
+      }
+
+      if invalid_candidates.is_empty() {
         continue
       }
 
-      return invalid_sum_candidates.into_iter().map(|(cell, invalid_values)| {
+      return invalid_candidates.into_iter().map(|(cell, invalid_values)| {
         self.build_simple_solution_step(
           invalid_values,
           vec![ Area::KillerCage(killer_cage_index) ],
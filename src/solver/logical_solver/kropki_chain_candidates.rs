@@ -45,10 +45,10 @@ impl KropkiChainCandidates {
     let mut steps_by_cell: HashMap<CellPosition, SolutionStep> = HashMap::new();
 
     for area in &solver.get_all_proper_areas() {
-      let mut dot_types = vec![ KropkiDotType::Consecutive, KropkiDotType::Double ];
+      let mut dot_types = vec![ KropkiDotType::Consecutive, KropkiDotType::Double, KropkiDotType::X, KropkiDotType::V ];
       if self.chain_limit {
         // Kropki pairs handle negative dots, the rest handled by chains
-        dot_types = vec![ KropkiDotType::Negative ];
+        dot_types = vec![ KropkiDotType::Negative, KropkiDotType::NegativeXV ];
       }
       for dot_type in dot_types {
         let area_steps = self.find_kropki_area_candidate_updates(solver, area, dot_type);
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::solver::logical_solver::combinations::cell_combinations_runner::CellCombinationsRunner;
+use crate::types::{Area, CellPosition, Rule, SolutionStep};
+use super::combinations::cell_combination_logic::CellCombinationLogic;
+use super::combinations::cell_combinations_runner::State;
+use super::technique::Technique;
+
+const MAX_EMPTY_CELLS: usize = 6;
+
+// X can't be a candidate in this cell because it violates the little killer sum
+pub struct LittleKillerCandidates;
+
+impl Technique for LittleKillerCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::LittleKillerCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.little_killers.iter().enumerate().flat_map(|(little_killer_index, little_killer)| {
+      let cells = little_killer.cells(solver.constraints.grid_size);
+
+      // Running the algorithm for long diagonals with few digits will take too much time, so
+      // only apply the sum bounds for now
+      let invalid_candidates = if solver.count_empty_cells_in_list(&cells) > MAX_EMPTY_CELLS {
+        Self::find_invalid_candidates_by_sum_bounds(solver, &cells, little_killer.sum)
+      } else {
+        let combination_logic = LittleKillerCombinationLogic::new(solver, &cells, little_killer.sum);
+        let mut runner = CellCombinationsRunner::new(solver, Box::new(combination_logic));
+        let (valid_candidates, _) = runner.run();
+        solver.cell_candidates_diff(&cells, valid_candidates)
+      };
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::LittleKiller(little_killer_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl LittleKillerCandidates {
+  // X can't be in a cell if the other cells can't make up the rest of the sum,
+  // even when they take their smallest or largest values
+  fn find_invalid_candidates_by_sum_bounds(solver: &Solver, cells: &[CellPosition], sum: u32) -> Vec<(CellPosition, Vec<u32>)> {
+    let cell_values: Vec<HashSet<u32>> = cells.iter().map(|cell| solver.get_cell_possible_values(cell)).collect();
+    let min_sum: u32 = cell_values.iter().map(|values| values.iter().min().copied().unwrap_or(0)).sum();
+    let max_sum: u32 = cell_values.iter().map(|values| values.iter().max().copied().unwrap_or(0)).sum();
+
+    cells.iter().zip(cell_values.iter()).filter_map(|(cell, values)| {
+      if solver.grid[cell.row][cell.col] != 0 {
+        return None
+      }
+
+      let other_min_sum = min_sum - values.iter().min().copied().unwrap_or(0);
+      let other_max_sum = max_sum - values.iter().max().copied().unwrap_or(0);
+
+      let invalid_values: Vec<u32> = values
+        .iter()
+        .filter(|&&value| value + other_min_sum > sum || value + other_max_sum < sum)
+        .copied()
+        .sorted()
+        .collect();
+
+      if invalid_values.is_empty() {
+        return None
+      }
+
+      Some((*cell, invalid_values))
+    }).collect()
+  }
+}
+
+struct LittleKillerCombinationLogic<'a> {
+  cells: &'a [CellPosition],
+  sum_left: u32,
+  // Bounds for the sum of the cells after each index, used to prune combinations early
+  min_sums_after: Vec<u32>,
+  max_sums_after: Vec<u32>,
+}
+
+impl<'a> LittleKillerCombinationLogic<'a> {
+  fn new(solver: &Solver, cells: &'a [CellPosition], sum: u32) -> LittleKillerCombinationLogic<'a> {
+    let mut min_sums_after = vec![ 0; cells.len() + 1 ];
+    let mut max_sums_after = vec![ 0; cells.len() + 1 ];
+    for (index, cell) in cells.iter().enumerate().rev() {
+      let values = solver.get_cell_possible_values(cell);
+      min_sums_after[index] = min_sums_after[index + 1] + values.iter().min().copied().unwrap_or(0);
+      max_sums_after[index] = max_sums_after[index + 1] + values.iter().max().copied().unwrap_or(0);
+    }
+
+    LittleKillerCombinationLogic {
+      cells,
+      sum_left: sum,
+      min_sums_after,
+      max_sums_after,
+    }
+  }
+}
+
+impl CellCombinationLogic for LittleKillerCombinationLogic<'_> {
+  fn cells(&self) -> Vec<CellPosition> {
+    self.cells.to_vec()
+  }
+
+  fn is_value_valid_candidate_in_cell(&self, _runner: &CellCombinationsRunner, value: u32, index: usize) -> bool {
+    if value > self.sum_left {
+      return false
+    }
+
+    let sum_left = self.sum_left - value;
+    self.min_sums_after[index + 1] <= sum_left && sum_left <= self.max_sums_after[index + 1]
+  }
+
+  // Digits can repeat on the diagonal, but only in cells that don't see each other
+  fn should_check_value_conflict(&self) -> bool { true }
+
+  fn advance_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    self.sum_left -= value;
+  }
+
+  fn restore_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    self.sum_left += value;
+  }
+}
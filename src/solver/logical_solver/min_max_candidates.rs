@@ -0,0 +1,86 @@
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{SolutionStep, Rule, Area, CellPosition};
+use super::technique::Technique;
+
+// A minimum cell must be smaller than the largest value of each neighbour and each
+// neighbour must be larger than the smallest value of the minimum cell (and vice versa for maximum)
+pub struct MinMaxCandidates;
+
+impl Technique for MinMaxCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::MinMaxCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    let minimum_cells = solver.constraints.minimum_cells.iter().map(|cell| (cell, true));
+    let maximum_cells = solver.constraints.maximum_cells.iter().map(|cell| (cell, false));
+
+    for (marked_cell, minimum) in minimum_cells.chain(maximum_cells) {
+      let cell_bounds = Self::find_cell_bounds(solver, marked_cell, minimum);
+
+      let steps: Vec<SolutionStep> = cell_bounds.into_iter().filter_map(|(cell, lower_bound, upper_bound)| {
+        if solver.grid[cell.row][cell.col] != 0 {
+          return None
+        }
+
+        let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+          .iter()
+          .filter(|&&value| value < lower_bound || value > upper_bound)
+          .copied()
+          .sorted()
+          .collect();
+
+        if invalid_values.is_empty() {
+          return None
+        }
+
+        Some(self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::Cell(marked_cell.row, marked_cell.col) ],
+          vec![ cell ],
+        ))
+      }).collect();
+
+      if !steps.is_empty() {
+        return steps
+      }
+    }
+
+    vec![]
+  }
+}
+
+impl MinMaxCandidates {
+  // Bounds for the marked cell, followed by bounds for each of its neighbours
+  fn find_cell_bounds(solver: &Solver, marked_cell: &CellPosition, minimum: bool) -> Vec<(CellPosition, u32, u32)> {
+    let neighbours = Solver::get_adjacent_cells(*marked_cell, solver.constraints.grid_size);
+    let marked_values = solver.get_cell_possible_values(marked_cell);
+
+    // If the bounds cross each other it should result in a cell with no candidates
+    let mut cell_bounds = if minimum {
+      let upper_bound = neighbours.iter().map(|cell| {
+        solver.get_cell_possible_values(cell).into_iter().max().unwrap_or(0).saturating_sub(1)
+      }).min().unwrap_or(u32::MAX);
+      vec![ (*marked_cell, 0, upper_bound) ]
+    } else {
+      let lower_bound = neighbours.iter().map(|cell| {
+        solver.get_cell_possible_values(cell).into_iter().min().unwrap_or(u32::MAX - 1) + 1
+      }).max().unwrap_or(0);
+      vec![ (*marked_cell, lower_bound, u32::MAX) ]
+    };
+
+    cell_bounds.extend(neighbours.into_iter().map(|cell| {
+      if minimum {
+        (cell, marked_values.iter().copied().min().unwrap_or(u32::MAX - 1) + 1, u32::MAX)
+      } else {
+        (cell, 0, marked_values.iter().copied().max().unwrap_or(0).saturating_sub(1))
+      }
+    }));
+
+    cell_bounds
+  }
+}
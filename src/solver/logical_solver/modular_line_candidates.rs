@@ -0,0 +1,31 @@
+use crate::solver::Solver;
+use crate::types::{Area, ModularLine, Rule, SolutionStep};
+use super::technique::Technique;
+
+// Cells that are 3 apart on the line must have the same residue mod 3
+// and each group of 3 consecutive cells uses all 3 residues.
+// X can't be a candidate in this cell because its residue can't be assigned to the cell's position.
+pub struct ModularLineCandidates;
+
+impl Technique for ModularLineCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::ModularLineCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.modular_lines.iter().enumerate().flat_map(|(line_index, line)| {
+      let invalid_candidates = solver.detect_invalid_group_line_candidates(line, ModularLine::value_group);
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::ModularLine(line_index) ],
+          vec![ cell ],
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
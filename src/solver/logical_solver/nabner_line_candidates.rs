@@ -0,0 +1,57 @@
+use crate::solver::Solver;
+use crate::solver::logical_solver::combinations::cell_combinations_runner::CellCombinationsRunner;
+use crate::types::{Area, CellPosition, NabnerLine, Rule, SolutionStep};
+use super::combinations::cell_combination_logic::CellCombinationLogic;
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because it violates the nabner rule:
+// digits on the line can't repeat and no two of them can be consecutive
+pub struct NabnerLineCandidates;
+
+impl Technique for NabnerLineCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::NabnerLineCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.nabner_lines.iter().enumerate().flat_map(|(line_index, line)| {
+      let combination_logic = NabnerLineCombinationLogic::new(line);
+      let mut runner = CellCombinationsRunner::new(solver, Box::new(combination_logic));
+      let (valid_candidates, _) = runner.run();
+      let invalid_candidates = solver.cell_candidates_diff(line, valid_candidates);
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::NabnerLine(line_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+struct NabnerLineCombinationLogic<'a> {
+  line: &'a NabnerLine,
+}
+
+impl<'a> NabnerLineCombinationLogic<'a> {
+  fn new(line: &'a NabnerLine) -> NabnerLineCombinationLogic<'a> {
+    NabnerLineCombinationLogic { line }
+  }
+}
+
+impl CellCombinationLogic for NabnerLineCombinationLogic<'_> {
+  fn cells(&self) -> Vec<CellPosition> {
+    self.line.to_vec()
+  }
+
+  fn is_value_valid_candidate_in_cell(&self, runner: &CellCombinationsRunner, value: u32, index: usize) -> bool {
+    runner.state.used_candidates[..index].iter().all(|&used_value| used_value.abs_diff(value) != 1)
+  }
+
+  fn should_check_all_cells_in_set(&self) -> bool { true }
+}
@@ -21,6 +21,11 @@ impl Technique for NakedSet {
 
     let areas = solver.get_all_areas(false, true, false, true, false);
     for area in areas {
+      if let Area::KillerCage(killer_cage_index) = area {
+        if !solver.constraints.killer_cages[killer_cage_index].unique {
+          continue
+        }
+      }
       let steps = self.run_in_area(area, solver);
       if !steps.is_empty() {
         return steps;
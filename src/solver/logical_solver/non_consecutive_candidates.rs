@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{SolutionStep, Rule, Area};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because every value of a non-consecutive
+// neighbour is consecutive to X
+pub struct NonConsecutiveCandidates;
+
+impl Technique for NonConsecutiveCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::NonConsecutiveCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+    if !solver.constraints.non_consecutive && !solver.constraints.diagonal_non_consecutive {
+      return vec![]
+    }
+
+    let mut steps: Vec<SolutionStep> = vec![];
+
+    for cell in solver.get_all_empty_cells() {
+      let mut removed_values: HashSet<u32> = HashSet::new();
+
+      for peer in solver.get_non_consecutive_peers(&cell) {
+        let peer_values = solver.get_cell_possible_values(&peer);
+        if peer_values.is_empty() {
+          continue
+        }
+
+        let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+          .difference(&removed_values)
+          .copied()
+          .filter(|&value| Self::is_value_blocked(value, &peer_values))
+          .sorted()
+          .collect();
+
+        if invalid_values.is_empty() {
+          continue
+        }
+
+        removed_values.extend(&invalid_values);
+        steps.push(self.build_solution_step(
+          vec![ peer ],
+          invalid_values,
+          vec![ Area::Cell(peer.row, peer.col) ],
+          vec![ cell ],
+        ));
+      }
+    }
+
+    steps
+  }
+}
+
+impl NonConsecutiveCandidates {
+  fn is_value_blocked(value: u32, peer_values: &HashSet<u32>) -> bool {
+    peer_values.iter().all(|&peer_value| peer_value.abs_diff(value) == 1)
+  }
+}
@@ -0,0 +1,35 @@
+use crate::solver::Solver;
+use crate::types::{Area, Rule, SolutionStep};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because the other cells in the cage
+// can't be assigned to reach the target with the cage's operator
+pub struct OperatorCageCandidates;
+
+impl Technique for OperatorCageCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::OperatorCageCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    for (operator_cage_index, operator_cage) in solver.constraints.operator_cages.iter().enumerate() {
+      let invalid_candidates = solver.detect_invalid_operator_candidates(operator_cage);
+      if invalid_candidates.is_empty() {
+        continue
+      }
+
+      return invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::OperatorCage(operator_cage_index) ],
+          vec![ cell ],
+        )
+      }).collect()
+    }
+
+    vec![]
+  }
+}
@@ -0,0 +1,52 @@
+use crate::solver::Solver;
+use crate::types::{CageOperator, CellPosition, OperatorCage};
+use super::combinations::cell_combination_logic::CellCombinationLogic;
+use super::combinations::cell_combinations_runner::CellCombinationsRunner;
+
+impl Solver {
+  pub fn detect_invalid_operator_candidates(&self, operator_cage: &OperatorCage) -> Vec<(CellPosition, Vec<u32>)> {
+    let cells: Vec<CellPosition> = operator_cage.region.to_vec();
+    match operator_cage.operator {
+      CageOperator::Add => self.detect_invalid_sum_candidates_with_repeats(&cells, operator_cage.value),
+      CageOperator::Multiply => self.detect_invalid_product_candidates_with_repeats(&cells, operator_cage.value),
+      CageOperator::Subtract | CageOperator::Divide => {
+        let mut combinations_runner = CellCombinationsRunner::new(
+          self, Box::new(PairOperationCombinationsLogic::new(&cells, operator_cage))
+        );
+        let (valid_candidates, _) = combinations_runner.run();
+        self.cell_candidates_diff(&cells, valid_candidates)
+      },
+    }
+  }
+}
+
+// Subtraction and division apply to cages with exactly 2 cells
+struct PairOperationCombinationsLogic<'a> {
+  cells: &'a Vec<CellPosition>,
+  operator_cage: &'a OperatorCage,
+}
+
+impl PairOperationCombinationsLogic<'_> {
+  pub fn new<'a>(cells: &'a Vec<CellPosition>, operator_cage: &'a OperatorCage) -> PairOperationCombinationsLogic<'a> {
+    PairOperationCombinationsLogic {
+      cells,
+      operator_cage,
+    }
+  }
+}
+
+impl CellCombinationLogic for PairOperationCombinationsLogic<'_> {
+  fn cells(&self) -> Vec<CellPosition> {
+    self.cells.to_owned()
+  }
+
+  fn is_value_valid_candidate_in_cell(&self, runner: &CellCombinationsRunner, value: u32, index: usize) -> bool {
+    if index == 0 {
+      return true
+    }
+    let first_value = runner.state.used_candidates[0];
+    self.operator_cage.is_valid_for_values(&[ first_value, value ])
+  }
+
+  fn should_check_value_conflict(&self) -> bool { true }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{Area, ParityLine, Rule, SolutionStep};
+use super::technique::Technique;
+
+// Cells on the line alternate between odd and even, so the parity of one cell
+// determines the parity of all other cells on the line.
+// Also, a line can't place more odd or even digits in an area than there are available.
+pub struct ParityLineCandidates;
+
+impl Technique for ParityLineCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::ParityLineCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.parity_lines.iter().enumerate().flat_map(|(line_index, line)| {
+      let cell_values: Vec<HashSet<u32>> = line.iter().map(|cell| solver.get_cell_possible_values(cell)).collect();
+
+      // The remainder (value % 2) of cells at even positions on the line
+      let mut valid_remainders: Vec<u32> = (0..2).filter(|&remainder| {
+        Self::fits_line_cells(&cell_values, remainder)
+      }).collect();
+
+      let mut areas = vec![ Area::ParityLine(line_index) ];
+      for area in solver.get_all_proper_areas() {
+        let area_valid_remainders: Vec<u32> = valid_remainders.iter().copied().filter(|&remainder| {
+          Self::fits_area_parity_counts(solver, line, &area, remainder)
+        }).collect();
+        if area_valid_remainders.len() < valid_remainders.len() {
+          valid_remainders = area_valid_remainders;
+          areas.push(area);
+        }
+      }
+
+      line.iter().enumerate().filter_map(|(cell_index, cell)| {
+        if solver.grid[cell.row][cell.col] != 0 {
+          return None
+        }
+
+        let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+          .iter()
+          .filter(|&&value| !valid_remainders.contains(&((value + cell_index as u32) % 2)))
+          .copied()
+          .sorted()
+          .collect();
+
+        if invalid_values.is_empty() {
+          return None
+        }
+
+        Some(self.build_simple_solution_step(
+          invalid_values,
+          areas.to_vec(),
+          vec![ *cell ],
+        ))
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl ParityLineCandidates {
+  fn fits_line_cells(cell_values: &[HashSet<u32>], remainder: u32) -> bool {
+    cell_values.iter().enumerate().all(|(cell_index, values)| {
+      values.iter().any(|value| (value + cell_index as u32) % 2 == remainder)
+    })
+  }
+
+  // Counts the odd and even digits the area needs with this parity assignment for the line
+  fn fits_area_parity_counts(solver: &Solver, line: &ParityLine, area: &Area, remainder: u32) -> bool {
+    let mut parity_counts = [ 0, 0 ];
+
+    for cell in solver.get_area_cells(area) {
+      if let Some(cell_index) = line.iter().position(|&line_cell| line_cell == cell) {
+        parity_counts[((remainder + cell_index as u32) % 2) as usize] += 1;
+        continue
+      }
+
+      let values = solver.get_cell_possible_values(&cell);
+      if !values.is_empty() && values.iter().map(|value| value % 2).all_equal() {
+        let value = values.into_iter().next().unwrap();
+        parity_counts[(value % 2) as usize] += 1;
+      }
+    }
+
+    let grid_size = solver.constraints.grid_size;
+    let even_count = grid_size / 2;
+    let odd_count = grid_size - even_count;
+
+    parity_counts[0] <= even_count && parity_counts[1] <= odd_count
+  }
+}
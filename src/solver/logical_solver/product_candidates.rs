@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+use crate::solver::Solver;
+use crate::types::CellPosition;
+use super::combinations::cell_combination_logic::CellCombinationLogic;
+use super::combinations::cell_combinations_runner::{CellCombinationsRunner, State};
+
+impl Solver {
+  pub fn detect_invalid_product_candidates(&self, cells: &Vec<CellPosition>, product: u32) -> Vec<(CellPosition, Vec<u32>)> {
+    let valid_candidates = self.compute_valid_product_candidates(cells, product);
+    self.cell_candidates_diff(cells, valid_candidates)
+  }
+
+  pub fn compute_valid_product_candidates(&self, cells: &Vec<CellPosition>, product: u32) -> Vec<HashSet<u32>> {
+    let mut combinations_runner = CellCombinationsRunner::new(
+      self, Box::new(ProductCombinationsLogic::new(cells, product, true))
+    );
+    let (valid_candidates, _) = combinations_runner.run();
+    valid_candidates
+  }
+
+  // Digits can repeat, unless the cells see each other
+  pub fn detect_invalid_product_candidates_with_repeats(&self, cells: &Vec<CellPosition>, product: u32) -> Vec<(CellPosition, Vec<u32>)> {
+    let mut combinations_runner = CellCombinationsRunner::new(
+      self, Box::new(ProductCombinationsLogic::new(cells, product, false))
+    );
+    let (valid_candidates, _) = combinations_runner.run();
+    self.cell_candidates_diff(cells, valid_candidates)
+  }
+}
+
+struct ProductCombinationsLogic<'a> {
+  product_left: u32,
+  cells: &'a Vec<CellPosition>,
+  unique: bool,
+}
+
+impl ProductCombinationsLogic<'_> {
+  pub fn new<'a>(cells: &'a Vec<CellPosition>, product: u32, unique: bool) -> ProductCombinationsLogic<'a> {
+    ProductCombinationsLogic {
+      cells,
+      product_left: product,
+      unique,
+    }
+  }
+}
+
+impl CellCombinationLogic for ProductCombinationsLogic<'_> {
+  fn cells(&self) -> Vec<CellPosition> {
+    self.cells.to_owned()
+  }
+
+  fn is_value_valid_candidate_in_cell(&self, runner: &CellCombinationsRunner, value: u32, index: usize) -> bool {
+    if !self.product_left.is_multiple_of(value) {
+      return false
+    }
+
+    // The last cell has to use up the whole product
+    let is_last_cell = index + 1 == runner.cells.len();
+    if is_last_cell && value != self.product_left {
+      return false
+    }
+
+    true
+  }
+
+  fn should_check_all_cells_in_set(&self) -> bool { self.unique }
+
+  fn should_check_value_conflict(&self) -> bool { !self.unique }
+
+  fn advance_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    self.product_left /= value;
+  }
+
+  fn restore_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    self.product_left *= value;
+  }
+}
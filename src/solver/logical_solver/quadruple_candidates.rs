@@ -0,0 +1,101 @@
+use crate::solver::Solver;
+use crate::solver::logical_solver::combinations::cell_combinations_runner::CellCombinationsRunner;
+use crate::types::{Area, CellPosition, Rule, SolutionStep};
+use super::combinations::cell_combination_logic::CellCombinationLogic;
+use super::combinations::cell_combinations_runner::State;
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because the listed quadruple
+// digits wouldn't fit in the remaining cells of the 2x2
+pub struct QuadrupleCandidates;
+
+impl Technique for QuadrupleCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::QuadrupleCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.quadruples.iter().enumerate().flat_map(|(quadruple_index, quadruple)| {
+      let cells = quadruple.cells();
+
+      let combination_logic = QuadrupleCombinationLogic::new(&cells, &quadruple.values, solver.constraints.grid_size);
+      let mut runner = CellCombinationsRunner::new(solver, Box::new(combination_logic));
+      let (valid_candidates, _) = runner.run();
+      let invalid_candidates = solver.cell_candidates_diff(&cells, valid_candidates);
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::Quadruple(quadruple_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+struct QuadrupleCombinationLogic<'a> {
+  cells: &'a [CellPosition],
+  // How many more times each value must appear
+  missing_value_counts: Vec<usize>,
+  missing_count: usize,
+  // Whether the value placed at each index covered a missing value
+  covered: Vec<bool>,
+}
+
+impl<'a> QuadrupleCombinationLogic<'a> {
+  fn new(cells: &'a [CellPosition], values: &[u32], grid_size: usize) -> QuadrupleCombinationLogic<'a> {
+    let mut missing_value_counts = vec![ 0; grid_size + 1 ];
+    for &value in values {
+      missing_value_counts[value as usize] += 1;
+    }
+
+    QuadrupleCombinationLogic {
+      cells,
+      missing_value_counts,
+      missing_count: values.len(),
+      covered: vec![],
+    }
+  }
+}
+
+impl CellCombinationLogic for QuadrupleCombinationLogic<'_> {
+  fn cells(&self) -> Vec<CellPosition> {
+    self.cells.to_vec()
+  }
+
+  fn is_value_valid_candidate_in_cell(&self, runner: &CellCombinationsRunner, value: u32, index: usize) -> bool {
+    let missing_count = if self.missing_value_counts[value as usize] > 0 {
+      self.missing_count - 1
+    } else {
+      self.missing_count
+    };
+
+    // Each remaining cell can cover at most one missing value
+    let cells_left_count = runner.cells.len() - index - 1;
+
+    missing_count <= cells_left_count
+  }
+
+  // Digits can repeat in the quadruple, but only in cells that don't see each other
+  fn should_check_value_conflict(&self) -> bool { true }
+
+  fn advance_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    let covered = self.missing_value_counts[value as usize] > 0;
+    if covered {
+      self.missing_value_counts[value as usize] -= 1;
+      self.missing_count -= 1;
+    }
+    self.covered.push(covered);
+  }
+
+  fn restore_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    if self.covered.pop().unwrap() {
+      self.missing_value_counts[value as usize] += 1;
+      self.missing_count += 1;
+    }
+  }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+use crate::solver::Solver;
+use crate::types::{Area, CellPosition, Rule, SolutionStep};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because there is no common total
+// for which every region segment of the line has a valid sum combination
+pub struct RegionSumLineCandidates;
+
+impl Technique for RegionSumLineCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::RegionSumLineCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    (0..solver.constraints.region_sum_lines.len()).flat_map(|line_index| {
+      let segments = solver.get_region_sum_line_segments(line_index);
+      // A line inside a single region doesn't restrict anything
+      if segments.len() < 2 {
+        return vec![]
+      }
+
+      let segments: Vec<(u32, Vec<CellPosition>)> = segments.into_iter().map(|segment| {
+        let fixed_sum: u32 = segment.iter().map(|cell| solver.grid[cell.row][cell.col]).sum();
+        let empty_cells: Vec<CellPosition> = segment.into_iter().filter(|cell| solver.grid[cell.row][cell.col] == 0).collect();
+        (fixed_sum, empty_cells)
+      }).collect();
+
+      let mut valid_candidates: Vec<Vec<HashSet<u32>>> = segments.iter().map(|(_, empty_cells)| {
+        vec![ HashSet::new(); empty_cells.len() ]
+      }).collect();
+
+      let grid_size = solver.constraints.grid_size as u32;
+      let max_sum = grid_size * (grid_size + 1) / 2;
+      for line_sum in 1..=max_sum {
+        let Some(sum_valid_candidates) = Self::find_line_sum_valid_candidates(solver, &segments, line_sum) else {
+          continue
+        };
+
+        for (segment_candidates, segment_sum_candidates) in valid_candidates.iter_mut().zip(sum_valid_candidates) {
+          for (cell_candidates, cell_sum_candidates) in segment_candidates.iter_mut().zip(segment_sum_candidates) {
+            cell_candidates.extend(cell_sum_candidates);
+          }
+        }
+      }
+
+      segments.iter().zip(valid_candidates).flat_map(|((_, empty_cells), segment_candidates)| {
+        solver.cell_candidates_diff(empty_cells, segment_candidates).into_iter().map(|(cell, invalid_values)| {
+          self.build_simple_solution_step(
+            invalid_values,
+            vec![ Area::RegionSumLine(line_index) ],
+            vec![ cell ]
+          )
+        }).collect::<Vec<_>>()
+      }).collect()
+    }).collect()
+  }
+}
+
+impl RegionSumLineCandidates {
+  // Returns the valid candidates of each segment if all segments can sum up to line_sum
+  fn find_line_sum_valid_candidates(solver: &Solver, segments: &[(u32, Vec<CellPosition>)], line_sum: u32) -> Option<Vec<Vec<HashSet<u32>>>> {
+    segments.iter().map(|(fixed_sum, empty_cells)| {
+      if *fixed_sum > line_sum {
+        return None
+      }
+      let sum_left = line_sum - fixed_sum;
+
+      if empty_cells.is_empty() {
+        return (sum_left == 0).then(Vec::new)
+      }
+
+      let segment_candidates = solver.compute_valid_sum_candidates(empty_cells, sum_left);
+      if segment_candidates.iter().any(|cell_candidates| cell_candidates.is_empty()) {
+        return None
+      }
+
+      Some(segment_candidates)
+    }).collect()
+  }
+}
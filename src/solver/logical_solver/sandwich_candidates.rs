@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{Area, CellPosition, Rule, SolutionStep};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because there is no placement of the 1 and the
+// largest digit (the crusts) such that the digits between them add up to the sandwich sum
+pub struct SandwichCandidates;
+
+impl Technique for SandwichCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::SandwichCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.sandwiches.iter().enumerate().flat_map(|(sandwich_index, sandwich)| {
+      let cells = sandwich.cells(solver.constraints.grid_size);
+      let valid_candidates = Self::find_valid_candidates(solver, &cells, sandwich.value);
+      let invalid_candidates = solver.cell_candidates_diff(&cells, valid_candidates);
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::Sandwich(sandwich_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl SandwichCandidates {
+  // Try each placement of the crusts and keep the values that are consistent with at least one of them
+  fn find_valid_candidates(solver: &Solver, cells: &[CellPosition], sum: u32) -> Vec<HashSet<u32>> {
+    let grid_size = solver.constraints.grid_size as u32;
+    let crusts = [ 1, grid_size ];
+    let cell_values: Vec<HashSet<u32>> = cells.iter().map(|cell| solver.get_cell_possible_values(cell)).collect();
+
+    let mut valid_candidates: Vec<HashSet<u32>> = vec![ HashSet::new(); cells.len() ];
+
+    for (start, end) in (0..cells.len()).tuple_combinations() {
+      for (start_value, end_value) in [ (1, grid_size), (grid_size, 1) ] {
+        if !cell_values[start].contains(&start_value) || !cell_values[end].contains(&end_value) {
+          continue
+        }
+
+        let inner_cells = cells[start + 1..end].to_vec();
+        if inner_cells.is_empty() && sum != 0 {
+          continue
+        }
+        let inner_valid_candidates = solver.compute_valid_sum_candidates_without_values(&inner_cells, sum, &crusts);
+        if inner_valid_candidates.iter().any(|values| values.is_empty()) {
+          continue
+        }
+
+        let outer_valid_candidates: Vec<(usize, HashSet<u32>)> = (0..start).chain(end + 1..cells.len()).map(|index| {
+          let values = cell_values[index].iter().copied().filter(|value| !crusts.contains(value)).collect();
+          (index, values)
+        }).collect();
+        if outer_valid_candidates.iter().any(|(_, values)| values.is_empty()) {
+          continue
+        }
+
+        valid_candidates[start].insert(start_value);
+        valid_candidates[end].insert(end_value);
+        for (index, values) in inner_valid_candidates.into_iter().enumerate() {
+          valid_candidates[start + 1 + index].extend(values);
+        }
+        for (index, values) in outer_valid_candidates {
+          valid_candidates[index].extend(values);
+        }
+      }
+    }
+
+    valid_candidates
+  }
+}
@@ -0,0 +1,150 @@
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::solver::logical_solver::combinations::cell_combinations_runner::CellCombinationsRunner;
+use crate::types::{Area, CellPosition, Rule, SolutionStep};
+use super::combinations::cell_combination_logic::CellCombinationLogic;
+use super::combinations::cell_combinations_runner::State;
+use super::technique::Technique;
+
+const MAX_EMPTY_CELLS: usize = 7;
+
+// X can't be a candidate in this cell because no arrangement of the row or
+// column with X in this cell shows exactly Y buildings from the clue
+pub struct SkyscraperCandidates;
+
+impl Technique for SkyscraperCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::SkyscraperCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.skyscrapers.iter().enumerate().flat_map(|(skyscraper_index, skyscraper)| {
+      let cells = skyscraper.cells(solver.constraints.grid_size);
+
+      // Running the algorithm for mostly empty rows will take too much time because
+      // there are too many permutations, so only apply the direct bounds for now
+      let invalid_candidates = if solver.count_empty_cells_in_list(&cells) > MAX_EMPTY_CELLS {
+        Self::find_invalid_candidates_by_distance(solver, &cells, skyscraper.value)
+      } else {
+        let combination_logic = SkyscraperCombinationLogic::new(&cells, skyscraper.value, solver.constraints.grid_size as u32);
+        let mut runner = CellCombinationsRunner::new(solver, Box::new(combination_logic));
+        let (valid_candidates, _) = runner.run();
+        solver.cell_candidates_diff(&cells, valid_candidates)
+      };
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::Skyscraper(skyscraper_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl SkyscraperCandidates {
+  // A clue of 1 needs the tallest building next to it and a clue of N needs the buildings
+  // in increasing order. Otherwise, a building at distance k from the clue can be at most
+  // N - clue + k tall, leaving room for enough visible buildings before it.
+  fn find_invalid_candidates_by_distance(solver: &Solver, cells: &[CellPosition], visible_count: u32) -> Vec<(CellPosition, Vec<u32>)> {
+    let grid_size = solver.constraints.grid_size as u32;
+
+    cells.iter().enumerate().filter_map(|(index, cell)| {
+      if solver.grid[cell.row][cell.col] != 0 {
+        return None
+      }
+
+      let distance = index as u32 + 1;
+      let (min_value, max_value) = if visible_count == 1 && distance == 1 {
+        (grid_size, grid_size)
+      } else if visible_count == grid_size {
+        (distance, distance)
+      } else {
+        (1, (grid_size + distance).saturating_sub(visible_count))
+      };
+
+      let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+        .iter()
+        .filter(|&&value| value < min_value || value > max_value)
+        .copied()
+        .sorted()
+        .collect();
+
+      if invalid_values.is_empty() {
+        return None
+      }
+
+      Some((*cell, invalid_values))
+    }).collect()
+  }
+}
+
+struct SkyscraperCombinationLogic<'a> {
+  cells: &'a [CellPosition],
+  grid_size: u32,
+  // The tallest building after each placed cell, the last one is the current maximum
+  max_values: Vec<u32>,
+  visible_count_left: u32,
+}
+
+impl<'a> SkyscraperCombinationLogic<'a> {
+  fn new(cells: &'a [CellPosition], visible_count: u32, grid_size: u32) -> SkyscraperCombinationLogic<'a> {
+    SkyscraperCombinationLogic {
+      cells,
+      grid_size,
+      max_values: vec![],
+      visible_count_left: visible_count,
+    }
+  }
+
+  fn max_value(&self) -> u32 {
+    self.max_values.last().copied().unwrap_or(0)
+  }
+}
+
+impl CellCombinationLogic for SkyscraperCombinationLogic<'_> {
+  fn cells(&self) -> Vec<CellPosition> {
+    self.cells.to_vec()
+  }
+
+  fn is_value_valid_candidate_in_cell(&self, runner: &CellCombinationsRunner, value: u32, index: usize) -> bool {
+    let max_value = self.max_value();
+    let (new_max_value, visible_count_left) = if value > max_value {
+      if self.visible_count_left == 0 {
+        return false
+      }
+      (value, self.visible_count_left - 1)
+    } else {
+      (max_value, self.visible_count_left)
+    };
+
+    // Each of the remaining cells can add at most one visible building, and only taller ones count
+    let cells_left_count = (runner.cells.len() - index - 1) as u32;
+    let max_visible_count_left = cells_left_count.min(self.grid_size - new_max_value);
+    // The tallest building will be visible wherever it is
+    let min_visible_count_left = if new_max_value < self.grid_size { 1 } else { 0 };
+
+    min_visible_count_left <= visible_count_left && visible_count_left <= max_visible_count_left
+  }
+
+  fn should_check_all_cells_in_set(&self) -> bool { true }
+
+  fn advance_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    let max_value = self.max_value();
+    if value > max_value {
+      self.visible_count_left -= 1;
+    }
+    self.max_values.push(value.max(max_value));
+  }
+
+  fn restore_state(&mut self, _state: &mut State, value: u32, _index: usize) {
+    self.max_values.pop();
+    if value > self.max_value() {
+      self.visible_count_left += 1;
+    }
+  }
+}
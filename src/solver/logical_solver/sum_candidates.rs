@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use crate::solver::Solver;
 use crate::types::CellPosition;
 use super::combinations::cell_combination_logic::CellCombinationLogic;
@@ -5,8 +6,27 @@ use super::combinations::cell_combinations_runner::{CellCombinationsRunner, Stat
 
 impl Solver {
   pub fn detect_invalid_sum_candidates(&self, cells: &Vec<CellPosition>, sum: u32) -> Vec<(CellPosition, Vec<u32>)> {
+    let valid_candidates = self.compute_valid_sum_candidates(cells, sum);
+    self.cell_candidates_diff(cells, valid_candidates)
+  }
+
+  pub fn compute_valid_sum_candidates(&self, cells: &Vec<CellPosition>, sum: u32) -> Vec<HashSet<u32>> {
+    self.compute_valid_sum_candidates_without_values(cells, sum, &[])
+  }
+
+  // Same as above, but none of the cells can contain any of the excluded values
+  pub fn compute_valid_sum_candidates_without_values(&self, cells: &Vec<CellPosition>, sum: u32, excluded_values: &[u32]) -> Vec<HashSet<u32>> {
     let mut combinations_runner = CellCombinationsRunner::new(
-      &self, Box::new(SumCombinationsLogic::new(cells, sum))
+      &self, Box::new(SumCombinationsLogic::new(cells, sum, excluded_values, true))
+    );
+    let (valid_candidates, _) = combinations_runner.run();
+    valid_candidates
+  }
+
+  // Digits can repeat, unless the cells see each other
+  pub fn detect_invalid_sum_candidates_with_repeats(&self, cells: &Vec<CellPosition>, sum: u32) -> Vec<(CellPosition, Vec<u32>)> {
+    let mut combinations_runner = CellCombinationsRunner::new(
+      self, Box::new(SumCombinationsLogic::new(cells, sum, &[], false))
     );
     let (valid_candidates, _) = combinations_runner.run();
     self.cell_candidates_diff(cells, valid_candidates)
@@ -16,13 +36,17 @@ impl Solver {
 struct SumCombinationsLogic<'a> {
   sum_left: u32,
   cells: &'a Vec<CellPosition>,
+  excluded_values: &'a [u32],
+  unique: bool,
 }
 
 impl SumCombinationsLogic<'_> {
-  pub fn new<'a>(cells: &'_ Vec<CellPosition>, sum: u32) -> SumCombinationsLogic<'_> {
+  pub fn new<'a>(cells: &'a Vec<CellPosition>, sum: u32, excluded_values: &'a [u32], unique: bool) -> SumCombinationsLogic<'a> {
     SumCombinationsLogic {
       cells,
       sum_left: sum,
+      excluded_values,
+      unique,
     }
   }
 }
@@ -36,9 +60,13 @@ impl CellCombinationLogic for SumCombinationsLogic<'_> {
     // TODO: adapt to grid_size
     // 9 + 8 + 7 + ... + (9 - x + 1) = x * (19 - x) / 2
     let cells_left_count: u32 = runner.cells.len() as u32 - index as u32 - 1;
-    let max_sum_left = cells_left_count * (19 - cells_left_count) / 2;
+    let max_sum_left = if self.unique {
+      cells_left_count * (19 - cells_left_count) / 2
+    } else {
+      cells_left_count * runner.solver.constraints.grid_size as u32
+    };
 
-    if value > self.sum_left {
+    if value > self.sum_left || self.excluded_values.contains(&value) {
       return false
     }
     if self.sum_left - value > max_sum_left {
@@ -48,7 +76,9 @@ impl CellCombinationLogic for SumCombinationsLogic<'_> {
     true
   }
 
-  fn should_check_all_cells_in_set(&self) -> bool { true }
+  fn should_check_all_cells_in_set(&self) -> bool { self.unique }
+
+  fn should_check_value_conflict(&self) -> bool { !self.unique }
 
   fn advance_state(&mut self, _state: &mut State, value: u32, _index: usize) {
     self.sum_left -= value;
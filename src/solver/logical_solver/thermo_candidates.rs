@@ -1,7 +1,7 @@
 use itertools::Itertools;
 
 use crate::solver::Solver;
-use crate::types::{SolutionStep, Rule, Area, Thermo};
+use crate::types::{SolutionStep, Rule, Area, CellPosition, Thermo};
 use super::technique::Technique;
 
 pub struct ThermoCandidates;
@@ -15,14 +15,13 @@ impl Technique for ThermoCandidates {
       return vec![]
     }
 
-    for (thermo_index, thermo) in solver.constraints.thermos.iter().enumerate() {
-      let lower_bounds = Self::find_thermo_lower_bounds(solver, &thermo);
-      let upper_bounds = Self::find_thermo_upper_bounds(solver, &thermo);
+    for (area, paths, slow) in Self::get_all_thermos(solver) {
+      let bounds = Self::find_thermo_bounds(solver, &paths, slow);
 
-      let steps: Vec<SolutionStep> = thermo.iter().enumerate().filter_map(|(cell_index, cell)| {
+      let steps: Vec<SolutionStep> = bounds.into_iter().filter_map(|(cell, lower_bound, upper_bound)| {
         let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
           .iter()
-          .filter(|&&value| value < lower_bounds[cell_index] || value > upper_bounds[cell_index])
+          .filter(|&&value| value < lower_bound || value > upper_bound)
           .copied()
           .sorted()
           .collect();
@@ -33,8 +32,8 @@ impl Technique for ThermoCandidates {
 
         Some(self.build_simple_solution_step(
           invalid_values,
-          vec![ Area::Thermo(thermo_index) ],
-          vec![ *cell ],
+          vec![ area.clone() ],
+          vec![ cell ],
         ))
       }).collect();
 
@@ -48,48 +47,115 @@ impl Technique for ThermoCandidates {
 }
 
 impl ThermoCandidates {
-  pub fn find_thermo_lower_bounds(solver: &Solver, thermo: &Thermo) -> Vec<u32> {
+  // Returns the area of each thermo, its bulb to tip paths and whether it is slow
+  pub fn get_all_thermos(solver: &Solver) -> Vec<(Area, Vec<Thermo>, bool)> {
+    let thermos = solver.constraints.thermos.iter().enumerate().map(|(thermo_index, thermo)| {
+      (Area::Thermo(thermo_index), vec![ thermo.clone() ], false)
+    });
+    let slow_thermos = solver.constraints.slow_thermos.iter().enumerate().map(|(thermo_index, thermo)| {
+      (Area::SlowThermo(thermo_index), vec![ thermo.clone() ], true)
+    });
+    let branching_thermos = solver.constraints.branching_thermos.iter().enumerate().map(|(thermo_index, thermo)| {
+      (Area::BranchingThermo(thermo_index), thermo.paths(), false)
+    });
+    thermos.chain(slow_thermos).chain(branching_thermos).collect()
+  }
+
+  // Lower and upper bounds of each cell, taken from all paths that go through it
+  // (e.g. the bulb of a branching thermo is limited by its longest branch)
+  pub fn find_thermo_bounds(solver: &Solver, paths: &[Thermo], slow: bool) -> Vec<(CellPosition, u32, u32)> {
+    let mut bounds: Vec<(CellPosition, u32, u32)> = vec![];
+    for path in paths {
+      let lower_bounds = Self::find_thermo_lower_bounds(solver, path, slow);
+      let upper_bounds = Self::find_thermo_upper_bounds(solver, path, slow);
+
+      for (cell_index, &cell) in path.iter().enumerate() {
+        let (lower_bound, upper_bound) = (lower_bounds[cell_index], upper_bounds[cell_index]);
+        match bounds.iter_mut().find(|(bounds_cell, _, _)| *bounds_cell == cell) {
+          Some((_, crt_lower_bound, crt_upper_bound)) => {
+            *crt_lower_bound = std::cmp::max(*crt_lower_bound, lower_bound);
+            *crt_upper_bound = std::cmp::min(*crt_upper_bound, upper_bound);
+          },
+          None => bounds.push((cell, lower_bound, upper_bound)),
+        }
+      }
+    }
+    bounds
+  }
+
+  pub fn find_thermo_lower_bounds(solver: &Solver, thermo: &Thermo, slow: bool) -> Vec<u32> {
     let max_value: u32 = solver.constraints.grid_size as u32 + 1;
     let mut current_min = 0;
-    thermo.iter().map(|cell| {
+    let mut bounds: Vec<u32> = vec![];
+    for (cell_index, cell) in thermo.iter().enumerate() {
       if solver.grid[cell.row][cell.col] != 0 {
         current_min = solver.grid[cell.row][cell.col];
       } else {
         let cell_candidates = solver.compute_cell_candidates(cell);
 
-        // If it reaches grid_sze it should result in a cell with no candidates
-        if current_min < max_value {
+        if slow {
+          current_min = Self::find_slow_thermo_bound(solver, &thermo[..cell_index], &bounds, cell, true);
+        } else if current_min < max_value {
+          // If it reaches grid_sze it should result in a cell with no candidates
           current_min += 1;
         }
-  
+
         let valid_candidates: Vec<_> = cell_candidates.into_iter().filter(|&val| val >= current_min).collect();
-  
+
         let lowest_candidate = valid_candidates.into_iter().min().unwrap_or(max_value);
         current_min = std::cmp::max(current_min, lowest_candidate);
       }
-      current_min
-    }).collect()
+      bounds.push(current_min);
+    }
+    bounds
   }
 
-  pub fn find_thermo_upper_bounds(solver: &Solver, thermo: &Thermo) -> Vec<u32> {
+  pub fn find_thermo_upper_bounds(solver: &Solver, thermo: &Thermo, slow: bool) -> Vec<u32> {
     let mut current_max = solver.constraints.grid_size as u32 + 1;
-    thermo.iter().rev().map(|cell| {
+    let reversed_thermo: Vec<CellPosition> = thermo.iter().rev().copied().collect();
+    let mut bounds: Vec<u32> = vec![];
+    for (cell_index, cell) in reversed_thermo.iter().enumerate() {
       if solver.grid[cell.row][cell.col] != 0 {
         current_max = solver.grid[cell.row][cell.col];
       } else {
         let cell_candidates = solver.compute_cell_candidates(cell);
 
-        // If it reaches 0 it should result in a cell with no candidates
-        if current_max > 0 {
+        if slow {
+          current_max = Self::find_slow_thermo_bound(solver, &reversed_thermo[..cell_index], &bounds, cell, false);
+        } else if current_max > 0 {
+          // If it reaches 0 it should result in a cell with no candidates
           current_max -= 1;
         }
-  
+
         let valid_candidates: Vec<_> = cell_candidates.into_iter().filter(|&val| val <= current_max).collect();
-  
+
         let highest_candidate = valid_candidates.into_iter().max().unwrap_or(0);
         current_max = std::cmp::min(current_max, highest_candidate);
       }
-      current_max
-    }).collect::<Vec<u32>>().into_iter().rev().collect()
+      bounds.push(current_max);
+    }
+    bounds.into_iter().rev().collect()
+  }
+
+  // On a slow thermo a cell can only have the same value as a previous cell if they don't see
+  // each other, but that applies to all previous cells, not just the neighbouring one
+  fn find_slow_thermo_bound(
+    solver: &Solver, previous_cells: &[CellPosition], previous_bounds: &[u32], cell: &CellPosition, lower: bool,
+  ) -> u32 {
+    let max_value: u32 = solver.constraints.grid_size as u32 + 1;
+    let bounds = previous_cells.iter().zip(previous_bounds).map(|(previous_cell, &bound)| {
+      let strict = solver.cells_affect_eachother(previous_cell, cell);
+      match (strict, lower) {
+        (false, _) => bound,
+        (true, true) => std::cmp::min(bound + 1, max_value),
+        (true, false) => bound.saturating_sub(1),
+      }
+    });
+
+    if lower {
+      bounds.max().unwrap_or(1)
+    } else {
+      bounds.min().unwrap_or(max_value - 1)
+    }
   }
 }
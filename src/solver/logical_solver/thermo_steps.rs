@@ -1,5 +1,5 @@
 use crate::solver::Solver;
-use crate::types::{SolutionStep, Rule, Area};
+use crate::types::{SolutionStep, Rule};
 use super::technique::Technique;
 use super::thermo_candidates::ThermoCandidates;
 
@@ -10,22 +10,19 @@ impl Technique for Thermo {
   fn get_rule(&self) -> Rule { Rule::Thermo }
 
   fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
-    for (thermo_index, thermo) in solver.constraints.thermos.iter().enumerate() {
-      let lower_bounds = ThermoCandidates::find_thermo_lower_bounds(solver, &thermo);
-      let upper_bounds = ThermoCandidates::find_thermo_upper_bounds(solver, &thermo);
-
-      for (cell_index, cell) in thermo.iter().enumerate() {
-        let valid_values: Vec<u32> = solver.compute_cell_candidates(cell)
+    for (area, paths, slow) in ThermoCandidates::get_all_thermos(solver) {
+      for (cell, lower_bound, upper_bound) in ThermoCandidates::find_thermo_bounds(solver, &paths, slow) {
+        let valid_values: Vec<u32> = solver.compute_cell_candidates(&cell)
           .into_iter()
-          .filter(|&value| lower_bounds[cell_index] <= value && value <= upper_bounds[cell_index])
+          .filter(|&value| lower_bound <= value && value <= upper_bound)
           .collect();
 
         if valid_values.len() == 1 {
           return vec![
             self.build_grid_solution_step(
-              vec![ *cell ],
+              vec![ cell ],
               valid_values,
-              vec![ Area::Thermo(thermo_index) ],
+              vec![ area ],
               &solver,
             )
           ]
@@ -0,0 +1,32 @@
+use crate::solver::Solver;
+use crate::types::{Area, Rule, SolutionStep, Whispers};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because it violates the whispers rule:
+// adjacent digits on the line must differ by at least the minimum difference
+pub struct WhispersCandidates;
+
+impl Technique for WhispersCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::WhispersCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    let min_difference = Whispers::min_difference(solver.constraints.grid_size);
+
+    solver.constraints.whispers.iter().enumerate().flat_map(|(whispers_index, whispers)| {
+      let invalid_candidates = solver.detect_invalid_difference_line_candidates(whispers, min_difference);
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::Whispers(whispers_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use crate::solver::Solver;
+use crate::types::{Area, CellPosition, Rule, SolutionStep};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because there is no value of the first
+// cell for which the first cells of the row or column add up to the X-sum
+pub struct XSumCandidates;
+
+impl Technique for XSumCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+  fn get_rule(&self) -> Rule { Rule::XSumCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.x_sums.iter().enumerate().flat_map(|(x_sum_index, x_sum)| {
+      let cells = x_sum.cells(solver.constraints.grid_size);
+      let valid_candidates = Self::find_valid_candidates(solver, &cells, x_sum.value);
+      let invalid_candidates = solver.cell_candidates_diff(&cells, valid_candidates);
+
+      invalid_candidates.into_iter().map(|(cell, invalid_values)| {
+        self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::XSum(x_sum_index) ],
+          vec![ cell ]
+        )
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl XSumCandidates {
+  // Try each value of the first cell and keep the values that are consistent with at least one of them
+  fn find_valid_candidates(solver: &Solver, cells: &[CellPosition], sum: u32) -> Vec<HashSet<u32>> {
+    let cell_values: Vec<HashSet<u32>> = cells.iter().map(|cell| solver.get_cell_possible_values(cell)).collect();
+
+    let mut valid_candidates: Vec<HashSet<u32>> = vec![ HashSet::new(); cells.len() ];
+
+    for &count in &cell_values[0] {
+      if count > sum {
+        continue
+      }
+
+      let summed_cells = cells[1..count as usize].to_vec();
+      if summed_cells.is_empty() && count != sum {
+        continue
+      }
+      let summed_valid_candidates = solver.compute_valid_sum_candidates_without_values(&summed_cells, sum - count, &[ count ]);
+      if summed_valid_candidates.iter().any(|values| values.is_empty()) {
+        continue
+      }
+
+      let other_valid_candidates: Vec<(usize, HashSet<u32>)> = (count as usize..cells.len()).map(|index| {
+        let values = cell_values[index].iter().copied().filter(|&value| value != count).collect();
+        (index, values)
+      }).collect();
+      if other_valid_candidates.iter().any(|(_, values)| values.is_empty()) {
+        continue
+      }
+
+      valid_candidates[0].insert(count);
+      for (index, values) in summed_valid_candidates.into_iter().enumerate() {
+        valid_candidates[index + 1].extend(values);
+      }
+      for (index, values) in other_valid_candidates {
+        valid_candidates[index].extend(values);
+      }
+    }
+
+    valid_candidates
+  }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::solver::Solver;
+use crate::types::{Area, Rule, SolutionStep, Zipper};
+use super::technique::Technique;
+
+// X can't be a candidate in this cell because there is no zipper sum for which
+// every pair of equidistant cells (and the center) can be completed
+pub struct ZipperCandidates;
+
+impl Technique for ZipperCandidates {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+
+  fn get_rule(&self) -> Rule { Rule::ZipperCandidates }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    if !solver.candidates_active {
+      return vec![]
+    }
+
+    solver.constraints.zippers.iter().enumerate().flat_map(|(zipper_index, zipper)| {
+      let valid_values = Self::find_valid_values(solver, zipper);
+
+      zipper.iter().zip(valid_values).filter_map(|(cell, cell_valid_values)| {
+        if solver.grid[cell.row][cell.col] != 0 {
+          return None
+        }
+
+        let invalid_values: Vec<u32> = solver.candidates[cell.row][cell.col]
+          .difference(&cell_valid_values)
+          .copied()
+          .sorted()
+          .collect();
+
+        if invalid_values.is_empty() {
+          return None
+        }
+
+        Some(self.build_simple_solution_step(
+          invalid_values,
+          vec![ Area::Zipper(zipper_index) ],
+          vec![ *cell ],
+        ))
+      }).collect::<Vec<_>>()
+    }).collect()
+  }
+}
+
+impl ZipperCandidates {
+  // Try each possible sum and keep the values that are consistent with at least one of them
+  fn find_valid_values(solver: &Solver, zipper: &Zipper) -> Vec<HashSet<u32>> {
+    let cell_values: Vec<HashSet<u32>> = zipper.iter().map(|cell| solver.get_cell_possible_values(cell)).collect();
+    let mut valid_values: Vec<HashSet<u32>> = vec![ HashSet::new(); zipper.len() ];
+
+    let sums: Vec<u32> = match zipper.center() {
+      Some(center) => cell_values[center].iter().copied().collect(),
+      None => (2..=2 * solver.constraints.grid_size as u32).collect(),
+    };
+
+    // Pair cells that see each other can't both be half of the sum
+    let pairs_affect_eachother: Vec<bool> = zipper.pairs().into_iter().map(|(left, right)| {
+      solver.cells_affect_eachother(&zipper[left], &zipper[right])
+    }).collect();
+
+    for sum in sums {
+      let pair_valid_values: Vec<(HashSet<u32>, HashSet<u32>)> = zipper.pairs().into_iter().zip(&pairs_affect_eachother).map(|((left, right), &affect_eachother)| {
+        (
+          Self::find_pair_valid_values(&cell_values[left], &cell_values[right], sum, affect_eachother),
+          Self::find_pair_valid_values(&cell_values[right], &cell_values[left], sum, affect_eachother),
+        )
+      }).collect();
+
+      if pair_valid_values.iter().any(|(left_values, _)| left_values.is_empty()) {
+        continue
+      }
+
+      for ((left, right), (left_values, right_values)) in zipper.pairs().into_iter().zip(pair_valid_values) {
+        valid_values[left].extend(left_values);
+        valid_values[right].extend(right_values);
+      }
+      if let Some(center) = zipper.center() {
+        valid_values[center].insert(sum);
+      }
+    }
+
+    valid_values
+  }
+
+  fn find_pair_valid_values(values: &HashSet<u32>, other_values: &HashSet<u32>, sum: u32, affect_eachother: bool) -> HashSet<u32> {
+    values.iter().copied().filter(|&value| {
+      value < sum && other_values.contains(&(sum - value)) && !(affect_eachother && 2 * value == sum)
+    }).collect()
+  }
+}
@@ -0,0 +1,77 @@
+use crate::solver::Solver;
+use crate::types::{Area, Rule, SolutionStep, Zipper};
+use super::technique::Technique;
+
+// We must put X in this cell because it completes the zipper sum with the cell on the other side
+pub struct ZipperValues;
+
+impl Technique for ZipperValues {
+  fn is_candidate_validity_update_step(&self) -> bool { true }
+
+  fn is_grid_step(&self) -> bool { true }
+
+  fn get_rule(&self) -> Rule { Rule::ZipperValues }
+
+  fn run(&self, solver: &Solver) -> Vec<SolutionStep> {
+    solver.constraints.zippers.iter().enumerate().flat_map(|(zipper_index, zipper)| {
+      let values = solver.get_area_values(&Area::Zipper(zipper_index));
+      let Some(sum) = Self::find_zipper_sum(zipper, &values) else {
+        return vec![]
+      };
+
+      let mut steps: Vec<SolutionStep> = vec![];
+      for (left, right) in zipper.pairs() {
+        // one is 0 and the other is not
+        if (values[left] == 0) == (values[right] == 0) {
+          continue
+        }
+        let (empty_index, known_value) = if values[left] == 0 { (left, values[right]) } else { (right, values[left]) };
+        if known_value >= sum || sum - known_value > solver.constraints.grid_size as u32 {
+          continue
+        }
+
+        steps.push(
+          self.build_grid_solution_step(
+            vec![ zipper[empty_index] ],
+            vec![ sum - known_value ],
+            vec![ Area::Zipper(zipper_index) ],
+            solver,
+          )
+        );
+      }
+
+      if let Some(center) = zipper.center() {
+        if values[center] == 0 && sum <= solver.constraints.grid_size as u32 {
+          steps.push(
+            self.build_grid_solution_step(
+              vec![ zipper[center] ],
+              vec![ sum ],
+              vec![ Area::Zipper(zipper_index) ],
+              solver,
+            )
+          );
+        }
+      }
+
+      steps
+    }).collect()
+  }
+}
+
+impl ZipperValues {
+  // The sum is known from the center digit or from a pair of filled cells
+  fn find_zipper_sum(zipper: &Zipper, values: &[u32]) -> Option<u32> {
+    if let Some(center) = zipper.center() {
+      if values[center] != 0 {
+        return Some(values[center])
+      }
+    }
+
+    zipper.pairs().into_iter().find_map(|(left, right)| {
+      if values[left] == 0 || values[right] == 0 {
+        return None
+      }
+      Some(values[left] + values[right])
+    })
+  }
+}
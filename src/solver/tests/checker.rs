@@ -11,3 +11,25 @@ mod odd_even;
 mod topbot;
 mod renban;
 mod palindrome;
+mod whispers;
+mod difference_line;
+mod between_line;
+mod region_sum_line;
+mod entropic_line;
+mod parity_line;
+mod zipper;
+mod modular_line;
+mod nabner_line;
+mod little_killer;
+mod sandwich;
+mod x_sum;
+mod skyscraper;
+mod xv;
+mod quadruple;
+mod non_consecutive;
+mod disjoint_groups;
+mod inequality;
+mod clone;
+mod operator_cage;
+mod fortress;
+mod min_max;
@@ -0,0 +1,85 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, BetweenLine, CellPosition, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints}};
+
+#[test]
+fn check_between_line_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_between_lines(
+      vec![
+        BetweenLine(vec![CellPosition::new(0, 0), CellPosition::new(1, 1), CellPosition::new(1, 2), CellPosition::new(0, 3)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_between_line_digit_outside_circles() {
+  let constraints = SudokuConstraints::new(6)
+    .with_between_lines(
+      vec![
+        BetweenLine(vec![CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::BetweenLine(0),
+        values: vec![2],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_between_line_digits_on_both_sides_of_circle() {
+  let constraints = SudokuConstraints::new(6)
+    .with_between_lines(
+      vec![
+        BetweenLine(vec![
+          CellPosition::new(2, 4), CellPosition::new(2, 3), CellPosition::new(2, 2),
+          CellPosition::new(2, 1), CellPosition::new(2, 0),
+        ]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 0, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_partially_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::BetweenLine(0),
+        values: vec![2, 5],
+      }
+    )
+  );
+}
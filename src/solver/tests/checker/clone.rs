@@ -0,0 +1,73 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, CloneGroup, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints}};
+
+#[test]
+fn check_clone_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_clones(
+      vec![
+        CloneGroup(vec![
+          vec![ CellPosition::new(1, 2), CellPosition::new(1, 3) ],
+          vec![ CellPosition::new(3, 0), CellPosition::new(3, 1) ],
+        ])
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_clone_wrong() {
+  let constraints = SudokuConstraints::new(6)
+    .with_clones(
+      vec![
+        CloneGroup(vec![
+          vec![ CellPosition::new(0, 4), CellPosition::new(0, 5) ],
+          vec![ CellPosition::new(5, 4), CellPosition::new(5, 5) ],
+        ])
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::CloneGroup(0),
+        values: vec![4, 5],
+      }
+    )
+  );
+}
+
+#[test]
+#[should_panic(expected = "clones")]
+fn check_clone_different_lengths() {
+  let constraints = SudokuConstraints::new(6)
+    .with_clones(
+      vec![
+        CloneGroup(vec![
+          vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ],
+          vec![ CellPosition::new(3, 3) ],
+        ]),
+      ]
+    );
+  Solver::new(constraints);
+}
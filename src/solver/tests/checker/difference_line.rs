@@ -0,0 +1,91 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, DifferenceLine, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints}};
+
+#[test]
+fn check_difference_line_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_difference_lines(
+      vec![
+        DifferenceLine {
+          cells: vec![CellPosition::new(0, 0), CellPosition::new(1, 0), CellPosition::new(2, 0)],
+          min_difference: 3,
+        },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_difference_line_wrong_difference() {
+  let constraints = SudokuConstraints::new(6)
+    .with_difference_lines(
+      vec![
+        DifferenceLine {
+          cells: vec![CellPosition::new(0, 0), CellPosition::new(1, 0), CellPosition::new(2, 1)],
+          min_difference: 3,
+        },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::DifferenceLine(0),
+        values: vec![1, 1],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_difference_line_wrong_partial_middle_digit() {
+  let constraints = SudokuConstraints::new(6)
+    .with_difference_lines(
+      vec![
+        DifferenceLine {
+          cells: vec![CellPosition::new(0, 0), CellPosition::new(1, 0), CellPosition::new(2, 0)],
+          min_difference: 4,
+        },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 0, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let partially_solved = solver.check_partially_solved();
+  assert_eq!(
+    partially_solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::DifferenceLine(0),
+        values: vec![4],
+      }
+    )
+  );
+}
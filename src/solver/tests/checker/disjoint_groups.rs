@@ -0,0 +1,42 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints}};
+
+#[test]
+fn check_disjoint_groups_correct() {
+  let constraints = SudokuConstraints::new(6).with_disjoint_groups();
+  let grid = Grid(vec![
+    vec![ 5, 1, 6, 4, 2, 3 ],
+    vec![ 2, 3, 4, 5, 1, 6 ],
+    vec![ 6, 4, 2, 1, 3, 5 ],
+    vec![ 1, 5, 3, 6, 4, 2 ],
+    vec![ 3, 6, 1, 2, 5, 4 ],
+    vec![ 4, 2, 5, 3, 6, 1 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_disjoint_groups_wrong() {
+  let constraints = SudokuConstraints::new(6).with_disjoint_groups();
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaValueConflict,
+        area: Area::DisjointGroup(0),
+        values: vec![6],
+      }
+    )
+  );
+}
@@ -12,6 +12,7 @@ fn check_killer_low_sum() {
             CellPosition::new(1, 0),
             CellPosition::new(2, 0),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -48,6 +49,7 @@ fn check_killer_high_sum() {
             CellPosition::new(1, 1),
             CellPosition::new(1, 2),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -84,6 +86,7 @@ fn check_killer_unique_digits() {
             CellPosition::new(1, 1),
             CellPosition::new(2, 1),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -120,6 +123,7 @@ fn check_killer_correct() {
             CellPosition::new(2, 1),
             CellPosition::new(2, 2),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -147,6 +151,7 @@ fn check_killer_partially_correct() {
             CellPosition::new(2, 1),
             CellPosition::new(2, 2),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -174,6 +179,7 @@ fn check_killer_partially_correct_exact_sum() {
             CellPosition::new(2, 1),
             CellPosition::new(2, 2),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -201,6 +207,7 @@ fn check_killer_partially_incorrect_high_sum() {
             CellPosition::new(2, 1),
             CellPosition::new(2, 2),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -223,3 +230,96 @@ fn check_killer_partially_incorrect_high_sum() {
     )
   );
 }
+
+#[test]
+fn check_killer_product() {
+  let constraints = SudokuConstraints::new(4)
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: None,
+          product: Some(6),
+          region: Region(vec![
+            CellPosition::new(0, 0),
+            CellPosition::new(1, 0),
+            CellPosition::new(2, 0),
+          ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 2, 1, 4, 3 ],
+    vec![ 3, 4, 1, 2 ],
+    vec![ 1, 2, 3, 4 ],
+    vec![ 4, 3, 2, 1 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_killer_wrong_product() {
+  let constraints = SudokuConstraints::new(4)
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: None,
+          product: Some(8),
+          region: Region(vec![
+            CellPosition::new(0, 0),
+            CellPosition::new(1, 0),
+            CellPosition::new(2, 0),
+          ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 2, 1, 4, 3 ],
+    vec![ 3, 4, 1, 2 ],
+    vec![ 1, 2, 3, 4 ],
+    vec![ 4, 3, 2, 1 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::KillerCage(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_killer_non_unique() {
+  let constraints = SudokuConstraints::new(4)
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: Some(6),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(0, 1),
+            CellPosition::new(1, 1),
+            CellPosition::new(1, 2),
+          ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 2, 1, 4, 3 ],
+    vec![ 3, 4, 1, 2 ],
+    vec![ 1, 2, 3, 4 ],
+    vec![ 4, 3, 2, 1 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
@@ -0,0 +1,93 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellDirection, CellPosition, Grid, InvalidStateReason, InvalidStateType, LittleKiller, SudokuConstraints}};
+
+#[test]
+fn check_little_killer_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 13, cell: CellPosition::new(0, 3), direction: CellDirection { row: 1, col: 1 } },
+        LittleKiller { sum: 19, cell: CellPosition::new(0, 0), direction: CellDirection { row: 1, col: 1 } },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_little_killer_wrong_sum() {
+  let constraints = SudokuConstraints::new(6)
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 12, cell: CellPosition::new(2, 5), direction: CellDirection { row: -1, col: -1 } },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::LittleKiller(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_little_killer_partial_sum_too_big() {
+  let constraints = SudokuConstraints::new(6)
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 10, cell: CellPosition::new(5, 0), direction: CellDirection { row: -1, col: 1 } },
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 5, 0, 0, 0, 0 ],
+    vec![ 2, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  assert_eq!(solver.check_partially_solved(), SolvedState::solved());
+
+  let grid = Grid(vec![
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 4, 0, 0, 0 ],
+    vec![ 0, 5, 0, 0, 0, 0 ],
+    vec![ 2, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = solver.with_grid(grid);
+  assert_eq!(
+    solver.check_partially_solved(),
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::LittleKiller(0),
+        values: vec![],
+      }
+    )
+  );
+}
@@ -0,0 +1,71 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints}};
+
+#[test]
+fn check_min_max_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_minimum_cells(vec![ CellPosition::new(1, 0), CellPosition::new(3, 4) ])
+    .with_maximum_cells(vec![ CellPosition::new(0, 0), CellPosition::new(1, 4) ]);
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_minimum_wrong() {
+  let constraints = SudokuConstraints::new(6)
+    .with_minimum_cells(vec![ CellPosition::new(1, 0), CellPosition::new(2, 0) ]);
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::CellInvalidValue,
+        area: Area::Cell(2, 0),
+        values: vec![4],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_maximum_wrong() {
+  let constraints = SudokuConstraints::new(6)
+    .with_maximum_cells(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]);
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::CellInvalidValue,
+        area: Area::Cell(0, 1),
+        values: vec![2],
+      }
+    )
+  );
+}
@@ -0,0 +1,82 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, NabnerLine, SudokuConstraints}};
+
+#[test]
+fn check_nabner_line_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_nabner_lines(
+      vec![
+        NabnerLine(vec![CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(1, 1)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_nabner_line_consecutive_digits() {
+  let constraints = SudokuConstraints::new(6)
+    .with_nabner_lines(
+      vec![
+        NabnerLine(vec![CellPosition::new(0, 1), CellPosition::new(0, 0), CellPosition::new(0, 2)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::NabnerLine(0),
+        values: vec![2, 3],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_nabner_line_repeated_digit() {
+  let constraints = SudokuConstraints::new(6)
+    .with_nabner_lines(
+      vec![
+        NabnerLine(vec![CellPosition::new(0, 1), CellPosition::new(1, 3), CellPosition::new(1, 5)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaValueConflict,
+        area: Area::NabnerLine(0),
+        values: vec![2],
+      }
+    )
+  );
+}
@@ -0,0 +1,67 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints}};
+
+#[test]
+fn check_non_consecutive_correct() {
+  let constraints = SudokuConstraints::new(6).with_non_consecutive();
+  let grid = Grid(vec![
+    vec![ 5, 1, 3, 6, 2, 4 ],
+    vec![ 2, 4, 6, 3, 5, 1 ],
+    vec![ 6, 2, 4, 1, 3, 5 ],
+    vec![ 3, 5, 1, 4, 6, 2 ],
+    vec![ 1, 3, 5, 2, 4, 6 ],
+    vec![ 4, 6, 2, 5, 1, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_non_consecutive_adjacent_wrong() {
+  let constraints = SudokuConstraints::new(6).with_non_consecutive();
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::CellInvalidValue,
+        area: Area::Cell(0, 1),
+        values: vec![2],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_non_consecutive_diagonal_wrong() {
+  let constraints = SudokuConstraints::new(6).with_diagonal_non_consecutive();
+  let grid = Grid(vec![
+    vec![ 5, 1, 3, 6, 2, 4 ],
+    vec![ 2, 4, 6, 3, 5, 1 ],
+    vec![ 6, 2, 4, 1, 3, 5 ],
+    vec![ 3, 5, 1, 4, 6, 2 ],
+    vec![ 1, 3, 5, 2, 4, 6 ],
+    vec![ 4, 6, 2, 5, 1, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::CellInvalidValue,
+        area: Area::Cell(0, 0),
+        values: vec![5],
+      }
+    )
+  );
+}
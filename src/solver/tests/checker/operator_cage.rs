@@ -0,0 +1,91 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CageOperator, CellPosition, Grid, InvalidStateReason, InvalidStateType, OperatorCage, Region, SudokuConstraints}};
+
+#[test]
+fn check_operator_cages_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_operator_cages(
+      vec![
+        OperatorCage::new(
+          CageOperator::Add,
+          9,
+          Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(1, 0) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          1,
+          Region(vec![ CellPosition::new(0, 4), CellPosition::new(0, 5) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Multiply,
+          12,
+          Region(vec![ CellPosition::new(1, 1), CellPosition::new(2, 1), CellPosition::new(3, 1) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Divide,
+          2,
+          Region(vec![ CellPosition::new(1, 4), CellPosition::new(2, 4) ]),
+        ),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_operator_cage_wrong_division() {
+  let constraints = SudokuConstraints::new(6)
+    .with_operator_cages(
+      vec![
+        OperatorCage::new(
+          CageOperator::Divide,
+          3,
+          Region(vec![ CellPosition::new(1, 4), CellPosition::new(2, 4) ]),
+        ),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::OperatorCage(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+#[should_panic(expected = "operator_cages")]
+fn check_operator_cage_subtract_needs_two_cells() {
+  let constraints = SudokuConstraints::new(6)
+    .with_operator_cages(
+      vec![
+        OperatorCage::new(
+          CageOperator::Subtract,
+          1,
+          Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2) ])
+        ),
+      ]
+    );
+  Solver::new(constraints);
+}
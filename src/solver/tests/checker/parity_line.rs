@@ -0,0 +1,112 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, ParityLine, SudokuConstraints}};
+
+#[test]
+fn check_parity_line_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_parity_lines(
+      vec![
+        ParityLine(vec![CellPosition::new(0, 1), CellPosition::new(0, 2), CellPosition::new(1, 1), CellPosition::new(1, 0)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_parity_line_same_parity() {
+  let constraints = SudokuConstraints::new(6)
+    .with_parity_lines(
+      vec![
+        ParityLine(vec![CellPosition::new(0, 1), CellPosition::new(0, 2), CellPosition::new(0, 3)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::ParityLine(0),
+        values: vec![3, 1],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_parity_line_partial_gap() {
+  let constraints = SudokuConstraints::new(6)
+    .with_parity_lines(
+      vec![
+        ParityLine(vec![CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 1, 0, 2, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  assert_eq!(
+    solver.check_partially_solved(),
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::CellInvalidValue,
+        area: Area::Cell(0, 2),
+        values: vec![2],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_parity_line_partial_even_cell_no_candidates() {
+  let constraints = SudokuConstraints::new(6)
+    .with_even_cells(vec![ CellPosition::new(0, 0) ])
+    .with_parity_lines(
+      vec![
+        ParityLine(vec![CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 0, 0, 3, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  // The 3 forces the first cell to be odd, but it is marked as even
+  assert_eq!(
+    solver.check_partially_solved(),
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::CellNoCandidates,
+        area: Area::Cell(0, 0),
+        values: vec![],
+      }
+    )
+  );
+}
@@ -0,0 +1,109 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, Quadruple, SudokuConstraints}};
+
+#[test]
+fn check_quadruple_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_quadruples(
+      vec![
+        Quadruple::new(CellPosition::new(0, 0), vec![1, 2, 4, 6]),
+        Quadruple::new(CellPosition::new(2, 2), vec![2, 2]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_quadruple_missing_value() {
+  let constraints = SudokuConstraints::new(6)
+    .with_quadruples(
+      vec![
+        Quadruple::new(CellPosition::new(0, 0), vec![1, 3]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Quadruple(0),
+        values: vec![3],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_quadruple_partial_not_enough_cells() {
+  let constraints = SudokuConstraints::new(6)
+    .with_quadruples(
+      vec![
+        Quadruple::new(CellPosition::new(0, 0), vec![1, 3, 5]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  assert_eq!(solver.check_partially_solved(), SolvedState::solved());
+
+  let grid = Grid(vec![
+    vec![ 6, 2, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = solver.with_grid(grid);
+  assert_eq!(
+    solver.check_partially_solved(),
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Quadruple(0),
+        values: vec![1, 3, 5],
+      }
+    )
+  );
+}
+
+#[test]
+#[should_panic(expected = "quadruples")]
+fn check_quadruple_outside_grid() {
+  let constraints = SudokuConstraints::new(6)
+    .with_quadruples(vec![ Quadruple::new(CellPosition::new(5, 2), vec![1, 2]) ]);
+  Solver::new(constraints);
+}
+
+#[test]
+#[should_panic(expected = "quadruples")]
+fn check_quadruple_value_too_big() {
+  let constraints = SudokuConstraints::new(6)
+    .with_quadruples(vec![ Quadruple::new(CellPosition::new(0, 0), vec![1, 7]) ]);
+  Solver::new(constraints);
+}
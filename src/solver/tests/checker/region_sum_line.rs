@@ -0,0 +1,82 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, RegionSumLine, SudokuConstraints}};
+
+#[test]
+fn check_region_sum_line_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_region_sum_lines(
+      vec![
+        RegionSumLine(vec![CellPosition::new(0, 1), CellPosition::new(0, 2), CellPosition::new(0, 3), CellPosition::new(0, 4)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_region_sum_line_different_sums() {
+  let constraints = SudokuConstraints::new(6)
+    .with_region_sum_lines(
+      vec![
+        RegionSumLine(vec![CellPosition::new(0, 1), CellPosition::new(0, 2), CellPosition::new(0, 3)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::RegionSumLine(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_region_sum_line_partial_segment_too_big() {
+  let constraints = SudokuConstraints::new(6)
+    .with_region_sum_lines(
+      vec![
+        RegionSumLine(vec![CellPosition::new(0, 2), CellPosition::new(0, 3), CellPosition::new(0, 4), CellPosition::new(1, 4)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 0, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_partially_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::RegionSumLine(0),
+        values: vec![],
+      }
+    )
+  );
+}
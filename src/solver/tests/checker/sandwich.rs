@@ -0,0 +1,94 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, Grid, InvalidStateReason, InvalidStateType, OutsideClue, OutsideCluePosition, SudokuConstraints}};
+
+#[test]
+fn check_sandwich_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_sandwiches(
+      vec![
+        OutsideClue::new(5, OutsideCluePosition::Left, 0),
+        OutsideClue::new(0, OutsideCluePosition::Top, 0),
+        OutsideClue::new(10, OutsideCluePosition::Right, 2),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_sandwich_wrong_sum() {
+  let constraints = SudokuConstraints::new(6)
+    .with_sandwiches(
+      vec![
+        OutsideClue::new(11, OutsideCluePosition::Left, 1),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Sandwich(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_sandwich_partial_sum_too_big() {
+  let constraints = SudokuConstraints::new(6)
+    .with_sandwiches(
+      vec![
+        OutsideClue::new(8, OutsideCluePosition::Right, 1),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 1, 4, 0, 0, 6, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  assert_eq!(solver.check_partially_solved(), SolvedState::solved());
+
+  let grid = Grid(vec![
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 1, 4, 5, 0, 6, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = solver.with_grid(grid);
+  assert_eq!(
+    solver.check_partially_solved(),
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Sandwich(0),
+        values: vec![],
+      }
+    )
+  );
+}
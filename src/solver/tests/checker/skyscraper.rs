@@ -0,0 +1,110 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, Grid, InvalidStateReason, InvalidStateType, OutsideClue, OutsideCluePosition, SudokuConstraints}};
+
+#[test]
+fn check_skyscraper_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_skyscrapers(
+      vec![
+        OutsideClue::new(1, OutsideCluePosition::Left, 0),
+        OutsideClue::new(2, OutsideCluePosition::Right, 0),
+        OutsideClue::new(4, OutsideCluePosition::Bottom, 0),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_skyscraper_wrong_count() {
+  let constraints = SudokuConstraints::new(6)
+    .with_skyscrapers(
+      vec![
+        OutsideClue::new(3, OutsideCluePosition::Top, 1),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Skyscraper(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_skyscraper_partial_too_many_visible() {
+  let constraints = SudokuConstraints::new(6)
+    .with_skyscrapers(
+      vec![
+        OutsideClue::new(1, OutsideCluePosition::Left, 1),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 1, 0, 4, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  assert_eq!(solver.check_partially_solved(), SolvedState::solved());
+
+  let grid = Grid(vec![
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 1, 3, 4, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+    vec![ 0, 0, 0, 0, 0, 0 ],
+  ]);
+  let solver = solver.with_grid(grid);
+  assert_eq!(
+    solver.check_partially_solved(),
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Skyscraper(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+#[should_panic(expected = "skyscrapers")]
+fn check_skyscraper_value_zero() {
+  let constraints = SudokuConstraints::new(6)
+    .with_skyscrapers(vec![ OutsideClue::new(0, OutsideCluePosition::Left, 1) ]);
+  Solver::new(constraints);
+}
+
+#[test]
+#[should_panic(expected = "skyscrapers")]
+fn check_skyscraper_value_too_big() {
+  let constraints = SudokuConstraints::new(6)
+    .with_skyscrapers(vec![ OutsideClue::new(7, OutsideCluePosition::Left, 1) ]);
+  Solver::new(constraints);
+}
@@ -1,4 +1,4 @@
-use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints, Thermo}};
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, BranchingThermo, CellPosition, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints, Thermo}};
 
 #[test]
 fn check_wrong_thermo() {
@@ -31,3 +31,122 @@ fn check_wrong_thermo() {
     )
   );
 }
+
+#[test]
+fn check_correct_slow_thermo() {
+  let constraints = SudokuConstraints::new(6)
+    .with_slow_thermos(
+      vec![
+        Thermo(vec![
+          CellPosition::new(1, 0),
+          CellPosition::new(2, 1),
+          CellPosition::new(2, 2),
+          CellPosition::new(3, 3),
+        ]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_wrong_slow_thermo() {
+  let constraints = SudokuConstraints::new(6)
+    .with_slow_thermos(
+      vec![
+        Thermo(vec![ CellPosition::new(0, 0), CellPosition::new(1, 1) ]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::SlowThermo(0),
+        values: vec![6, 4],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_correct_branching_thermo() {
+  let constraints = SudokuConstraints::new(6)
+    .with_branching_thermos(
+      vec![
+        BranchingThermo::new(
+          CellPosition::new(2, 1),
+          vec![
+            vec![ CellPosition::new(2, 2), CellPosition::new(3, 1), CellPosition::new(4, 1), CellPosition::new(5, 1) ],
+            vec![ CellPosition::new(1, 1), CellPosition::new(1, 2) ],
+          ],
+        ),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_wrong_branching_thermo() {
+  let constraints = SudokuConstraints::new(6)
+    .with_branching_thermos(
+      vec![
+        BranchingThermo::new(
+          CellPosition::new(1, 1),
+          vec![
+            vec![ CellPosition::new(1, 2) ],
+            vec![ CellPosition::new(2, 1) ],
+          ],
+        ),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::BranchingThermo(0),
+        values: vec![4, 1],
+      }
+    )
+  );
+}
@@ -0,0 +1,103 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, SudokuConstraints, Whispers}};
+
+#[test]
+fn check_whispers_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_whispers(
+      vec![
+        Whispers(vec![CellPosition::new(0, 1), CellPosition::new(0, 0), CellPosition::new(1, 0)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_whispers_wrong_difference() {
+  let constraints = SudokuConstraints::new(6)
+    .with_whispers(
+      vec![
+        Whispers(vec![CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(1, 2)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Whispers(0),
+        values: vec![2, 5],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_whispers_wrong_partial_middle_digit() {
+  let constraints = SudokuConstraints::new(6)
+    .with_whispers(
+      vec![
+        Whispers(vec![CellPosition::new(0, 2), CellPosition::new(0, 3)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 0, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let partially_solved = solver.check_partially_solved();
+  assert_eq!(
+    partially_solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::Whispers(0),
+        values: vec![3],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_whispers_correct_partial() {
+  let constraints = SudokuConstraints::new(6)
+    .with_whispers(
+      vec![
+        Whispers(vec![CellPosition::new(0, 1), CellPosition::new(0, 0), CellPosition::new(1, 0)]),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 0, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let partially_solved = solver.check_partially_solved();
+  assert_eq!(partially_solved, SolvedState::solved());
+}
@@ -0,0 +1,167 @@
+use crate::{solver::{Solver, checker::SolvedState}, types::{Area, CellPosition, Grid, InvalidStateReason, InvalidStateType, KropkiDot, SudokuConstraints}};
+
+#[test]
+fn check_xv_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_kropki_dots(
+      vec![
+        KropkiDot::x(CellPosition::new(4, 2), CellPosition::new(4, 3)),
+        KropkiDot::v(CellPosition::new(0, 1), CellPosition::new(0, 2)),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_xv_x_incorrect() {
+  let constraints = SudokuConstraints::new(6)
+    .with_kropki_dots(
+      vec![
+        KropkiDot::x(CellPosition::new(2, 3), CellPosition::new(2, 4)),
+      ]
+    );
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::KropkiDot(0),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_xv_negative_condition_correct() {
+  let constraints = SudokuConstraints::new(6)
+    .with_kropki_dots(
+      vec![
+        KropkiDot::v(CellPosition::new(0, 1), CellPosition::new(0, 2)),
+        KropkiDot::v(CellPosition::new(0, 3), CellPosition::new(0, 4)),
+        KropkiDot::x(CellPosition::new(0, 4), CellPosition::new(1, 4)),
+        KropkiDot::v(CellPosition::new(1, 0), CellPosition::new(1, 1)),
+        KropkiDot::v(CellPosition::new(1, 0), CellPosition::new(2, 0)),
+        KropkiDot::v(CellPosition::new(1, 1), CellPosition::new(2, 1)),
+        KropkiDot::v(CellPosition::new(2, 0), CellPosition::new(2, 1)),
+        KropkiDot::x(CellPosition::new(2, 5), CellPosition::new(3, 5)),
+        KropkiDot::x(CellPosition::new(3, 2), CellPosition::new(4, 2)),
+        KropkiDot::v(CellPosition::new(3, 4), CellPosition::new(3, 5)),
+        KropkiDot::v(CellPosition::new(3, 5), CellPosition::new(4, 5)),
+        KropkiDot::v(CellPosition::new(4, 0), CellPosition::new(5, 0)),
+        KropkiDot::x(CellPosition::new(4, 2), CellPosition::new(4, 3)),
+        KropkiDot::v(CellPosition::new(4, 2), CellPosition::new(5, 2)),
+        KropkiDot::x(CellPosition::new(4, 3), CellPosition::new(5, 3)),
+        KropkiDot::v(CellPosition::new(5, 2), CellPosition::new(5, 3)),
+      ]
+    )
+    .with_xv_negative();
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  assert_eq!(solved, SolvedState::solved());
+}
+
+#[test]
+fn check_xv_negative_condition_incorrect() {
+  let constraints = SudokuConstraints::new(6)
+    .with_kropki_dots(
+      vec![
+        KropkiDot::v(CellPosition::new(0, 3), CellPosition::new(0, 4)),
+        KropkiDot::x(CellPosition::new(0, 4), CellPosition::new(1, 4)),
+        KropkiDot::v(CellPosition::new(1, 0), CellPosition::new(1, 1)),
+        KropkiDot::v(CellPosition::new(1, 0), CellPosition::new(2, 0)),
+        KropkiDot::v(CellPosition::new(1, 1), CellPosition::new(2, 1)),
+        KropkiDot::v(CellPosition::new(2, 0), CellPosition::new(2, 1)),
+        KropkiDot::x(CellPosition::new(2, 5), CellPosition::new(3, 5)),
+        KropkiDot::x(CellPosition::new(3, 2), CellPosition::new(4, 2)),
+        KropkiDot::v(CellPosition::new(3, 4), CellPosition::new(3, 5)),
+        KropkiDot::v(CellPosition::new(3, 5), CellPosition::new(4, 5)),
+        KropkiDot::v(CellPosition::new(4, 0), CellPosition::new(5, 0)),
+        KropkiDot::x(CellPosition::new(4, 2), CellPosition::new(4, 3)),
+        KropkiDot::v(CellPosition::new(4, 2), CellPosition::new(5, 2)),
+        KropkiDot::x(CellPosition::new(4, 3), CellPosition::new(5, 3)),
+        KropkiDot::v(CellPosition::new(5, 2), CellPosition::new(5, 3)),
+      ]
+    )
+    .with_xv_negative();
+  let grid = Grid(vec![
+    vec![ 6, 2, 3, 1, 4, 5 ],
+    vec![ 1, 4, 5, 3, 6, 2 ],
+    vec![ 4, 1, 2, 5, 3, 6 ],
+    vec![ 5, 3, 6, 2, 1, 4 ],
+    vec![ 3, 5, 4, 6, 2, 1 ],
+    vec![ 2, 6, 1, 4, 5, 3 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  // The unmarked 2 and 3 in the first row sum to 5
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::KropkiDot(17),
+        values: vec![],
+      }
+    )
+  );
+}
+
+#[test]
+fn check_xv_dots_dont_prevent_kropki_negative() {
+  let constraints = SudokuConstraints::new(4)
+    .with_kropki_dots(
+      vec![
+        KropkiDot::consecutive(CellPosition::new(0, 0), CellPosition::new(0, 1)),
+        KropkiDot::v(CellPosition::new(0, 0), CellPosition::new(1, 0)),
+      ]
+    )
+    .with_kropki_negative();
+  let grid = Grid(vec![
+    vec![ 2, 1, 4, 3 ],
+    vec![ 3, 4, 1, 2 ],
+    vec![ 1, 2, 3, 4 ],
+    vec![ 4, 3, 2, 1 ],
+  ]);
+  let solver = Solver::new(constraints).with_grid(grid);
+  let solved = solver.check_solved();
+  // The V dot is satisfied, but 2 and 3 are still consecutive without a kropki dot
+  assert_eq!(
+    solved,
+    SolvedState::unsolved(
+      InvalidStateReason {
+        state_type: InvalidStateType::AreaConstraint,
+        area: Area::KropkiDot(2),
+        values: vec![],
+      }
+    )
+  );
+}
@@ -18,6 +18,7 @@ mod common_peer_elimination_kropki;
 mod kropki_advanced_candidates;
 mod killer_candidates;
 mod killer45;
+mod killer45_product;
 mod kropki_pairs;
 mod kropki_chains;
 mod turbot_fish;
@@ -32,4 +33,27 @@ mod palindrome_candidates;
 mod phistomefel_ring;
 mod nishio_forcing_chains;
 mod adhoc_naked_set;
+mod whispers_candidates;
+mod difference_line_candidates;
+mod between_line_candidates;
+mod region_sum_line_candidates;
+mod entropic_line_candidates;
+mod parity_line_candidates;
+mod zipper_values;
+mod zipper_candidates;
+mod modular_line_candidates;
+mod nabner_line_candidates;
+mod little_killer_candidates;
+mod sandwich_candidates;
+mod x_sum_candidates;
+mod skyscraper_candidates;
+mod xv_candidates;
+mod quadruple_candidates;
+mod non_consecutive_candidates;
+mod inequality_candidates;
+mod clone_values;
+mod clone_candidates;
+mod operator_cage_candidates;
+mod fortress_candidates;
+mod min_max_candidates;
 mod puzzles;
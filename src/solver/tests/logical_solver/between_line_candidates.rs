@@ -0,0 +1,52 @@
+use crate::{solver::{Solver, logical_solver::{between_line_candidates::BetweenLineCandidates, candidates::Candidates, technique::Technique}}, types::{Area, BetweenLine, CellPosition, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_between_line_candidates_one_circle() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 2)]
+    )
+    .with_between_lines(
+      vec![
+        BetweenLine(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = BetweenLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  // The other circle must be at least 4 to leave room for the middle digit
+  let expected = [ (CellPosition::new(0, 1), vec![1, 9]), (CellPosition::new(0, 2), vec![1, 3]) ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::BetweenLineCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+    assert_eq!(step.areas, vec![ Area::BetweenLine(0) ]);
+  }
+}
+
+#[test]
+fn check_between_line_candidates_both_circles() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 3), FixedNumber::new(2, 2, 7)]
+    )
+    .with_between_lines(
+      vec![
+        BetweenLine(vec![ CellPosition::new(0, 0), CellPosition::new(1, 1), CellPosition::new(2, 2) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = BetweenLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+
+  let step = &steps[0];
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(1, 1) ]);
+  assert_eq!(step.values, vec![1, 2, 8, 9]);
+  solver.apply_rule(step);
+  assert_eq!(solver.candidates[1][1].len(), 3);
+}
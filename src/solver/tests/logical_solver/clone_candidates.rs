@@ -0,0 +1,37 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, clone_candidates::CloneCandidates, technique::Technique}}, types::{Area, CellPosition, CloneGroup, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_clone_candidates() {
+  let constraints = SudokuConstraints::new(4)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 2, 1), FixedNumber::new(3, 3, 2)]
+    )
+    .with_clones(
+      vec![
+        CloneGroup(vec![
+          vec![ CellPosition::new(0, 0) ],
+          vec![ CellPosition::new(3, 1) ],
+        ])
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = CloneCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  let step = &steps[0];
+  assert_eq!(step.rule, Rule::CloneCandidates);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(step.values, vec![ 2 ]);
+  assert_eq!(step.areas, vec![ Area::CloneGroup(0) ]);
+
+  let step = &steps[1];
+  assert_eq!(step.rule, Rule::CloneCandidates);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(3, 1) ]);
+  assert_eq!(step.values, vec![ 1 ]);
+  assert_eq!(step.areas, vec![ Area::CloneGroup(0) ]);
+
+  solver.apply_rules(&steps);
+  assert_eq!(solver.candidates[0][0], solver.candidates[3][1]);
+}
@@ -0,0 +1,38 @@
+use crate::{solver::{Solver, logical_solver::{clone_values::CloneValues, technique::Technique}}, types::{Area, CellPosition, CloneGroup, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_clone_values() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 1), FixedNumber::new(4, 5, 2)]
+    )
+    .with_clones(
+      vec![
+        CloneGroup(vec![
+          vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ],
+          vec![ CellPosition::new(4, 4), CellPosition::new(4, 5) ],
+          vec![ CellPosition::new(8, 7), CellPosition::new(8, 8) ],
+        ])
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  let steps = CloneValues.run(&solver);
+  assert_eq!(steps.len(), 4);
+
+  let expected = [
+    (CellPosition::new(4, 4), 1),
+    (CellPosition::new(8, 7), 1),
+    (CellPosition::new(0, 1), 2),
+    (CellPosition::new(8, 8), 2),
+  ];
+  for (step, (cell, value)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::CloneValues);
+    assert_eq!(step.cells, vec![ cell ]);
+    assert_eq!(step.values, vec![ value ]);
+    assert_eq!(step.areas, vec![ Area::CloneGroup(0) ]);
+    assert_eq!(solver.grid[cell.row][cell.col], 0);
+    solver.apply_rule(step);
+    assert_eq!(solver.grid[cell.row][cell.col], value);
+  }
+}
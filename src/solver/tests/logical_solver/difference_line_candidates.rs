@@ -0,0 +1,73 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, difference_line_candidates::DifferenceLineCandidates, technique::Technique}}, types::{Area, CellPosition, DifferenceLine, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_difference_line_candidates_dutch_whispers() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 5)]
+    )
+    .with_difference_lines(
+      vec![
+        DifferenceLine {
+          cells: vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ],
+          min_difference: 4,
+        },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = DifferenceLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+
+  let step = &steps[0];
+  assert_eq!(step.rule, Rule::DifferenceLineCandidates);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(0, 1) ]);
+  assert_eq!(step.values, vec![2, 3, 4, 6, 7, 8]);
+  assert_eq!(step.areas, vec![ Area::DifferenceLine(0) ]);
+  solver.apply_rule(step);
+  assert_eq!(solver.candidates[0][1].len(), 2);
+}
+
+#[test]
+fn check_difference_line_candidates_custom_difference() {
+  let cells = vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2) ];
+  let constraints = SudokuConstraints::new(9)
+    .with_difference_lines(
+      vec![
+        DifferenceLine { cells: cells.clone(), min_difference: 7 },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = DifferenceLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  // The middle cell can't be 2 or 8 because both of its neighbours would have to be 9 or 1
+  let expected_values = [ vec![3, 4, 5, 6, 7], vec![2, 3, 4, 5, 6, 7, 8], vec![3, 4, 5, 6, 7] ];
+  for (index, step) in steps.into_iter().enumerate() {
+    assert_eq!(step.rule, Rule::DifferenceLineCandidates);
+    assert_eq!(step.affected_cells, vec![ cells[index] ]);
+    assert_eq!(step.values, expected_values[index]);
+    assert_eq!(step.areas, vec![ Area::DifferenceLine(0) ]);
+  }
+}
+
+#[test]
+fn check_difference_line_candidates_empty_line() {
+  let constraints = SudokuConstraints::new(9)
+    .with_difference_lines(
+      vec![
+        DifferenceLine {
+          cells: vec![],
+          min_difference: 4,
+        },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = DifferenceLineCandidates.run(&solver);
+  assert!(steps.is_empty());
+}
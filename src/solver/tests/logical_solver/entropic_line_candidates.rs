@@ -0,0 +1,57 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, entropic_line_candidates::EntropicLineCandidates, technique::Technique}}, types::{Area, CellPosition, EntropicLine, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_entropic_line_candidates_repeating_pattern() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 2)]
+    )
+    .with_entropic_lines(
+      vec![
+        EntropicLine(vec![
+          CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2), CellPosition::new(1, 1),
+        ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = EntropicLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  // The 4th cell must be low like the 1st one
+  let expected = [
+    (CellPosition::new(0, 1), vec![1, 3]),
+    (CellPosition::new(0, 2), vec![1, 3]),
+    (CellPosition::new(1, 1), vec![4, 5, 6, 7, 8, 9]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::EntropicLineCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+    assert_eq!(step.areas, vec![ Area::EntropicLine(0) ]);
+  }
+}
+
+#[test]
+fn check_entropic_line_candidates_6x6_groups() {
+  let constraints = SudokuConstraints::new(6)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 3)]
+    )
+    .with_entropic_lines(
+      vec![
+        EntropicLine(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = EntropicLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+
+  // 3 and 4 are both middle digits in 6x6
+  let step = &steps[0];
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(0, 1) ]);
+  assert_eq!(step.values, vec![4]);
+}
@@ -0,0 +1,32 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, fortress_candidates::FortressCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_fortress_candidates_empty_grid() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fortress_cells(vec![ CellPosition::new(4, 4) ]);
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&Candidates.run(&solver)[0]);
+  let steps = FortressCandidates.run(&solver);
+  assert_eq!(steps.len(), 5);
+  assert!(steps.iter().all(|step| step.rule == Rule::FortressCandidates));
+  assert!(steps.iter().all(|step| step.areas == vec![ Area::FortressCell(0) ]));
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(4, 4) ]);
+  assert_eq!(steps[0].values, vec![ 1 ]);
+  assert!(steps[1..].iter().all(|step| step.values == vec![ 9 ]));
+}
+
+#[test]
+fn check_fortress_candidates_fixed_neighbour() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(vec![ FixedNumber::new(0, 1, 7) ])
+    .with_fortress_cells(vec![ CellPosition::new(0, 0), CellPosition::new(1, 0) ]);
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&Candidates.run(&solver)[0]);
+  let steps = FortressCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(steps[0].values, vec![ 1, 2, 3, 4, 5, 6 ]);
+  assert_eq!(steps[0].areas, vec![ Area::FortressCell(0) ]);
+}
@@ -215,3 +215,28 @@ fn check_hidden_single_anti_king() {
   let final_value = solver.grid[row][col];
   assert!(final_value == 2);
 }
+
+#[test]
+fn check_hidden_single_disjoint_group() {
+  let constraints = SudokuConstraints::new(6)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(1, 5, 1),
+        FixedNumber::new(2, 1, 1),
+        FixedNumber::new(4, 4, 1),
+      ]
+    )
+    .with_disjoint_groups();
+  let mut solver = Solver::new(constraints);
+
+  let steps = HiddenSingles.run(&solver);
+  let step = steps.iter().find(|step| step.areas == vec![Area::DisjointGroup(0)]).unwrap();
+  assert_eq!(step.values, vec![1]);
+  assert_eq!(step.cells, vec![
+    CellPosition::new(0, 0), CellPosition::new(1, 5), CellPosition::new(2, 1), CellPosition::new(4, 4),
+  ]);
+  assert!(step.affected_cells.is_empty());
+
+  solver.apply_rule(step);
+  assert_eq!(solver.grid[0][0], 1);
+}
@@ -0,0 +1,60 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, inequality_candidates::InequalityCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, Inequality, Rule, SudokuConstraints}};
+
+#[test]
+fn check_inequality_candidates_chain() {
+  let constraints = SudokuConstraints::new(9)
+    .with_inequalities(
+      vec![
+        Inequality::new(CellPosition::new(1, 0), CellPosition::new(1, 1)),
+        Inequality::new(CellPosition::new(0, 0), CellPosition::new(1, 0)),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = InequalityCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  let expected = [
+    (CellPosition::new(0, 0), vec![8, 9]),
+    (CellPosition::new(1, 0), vec![1, 9]),
+    (CellPosition::new(1, 1), vec![1, 2]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::InequalityCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+    assert_eq!(step.areas, vec![ Area::Inequality(0), Area::Inequality(1) ]);
+  }
+}
+
+#[test]
+fn check_inequality_candidates_fixed_end() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(1, 1, 3) ]
+    )
+    .with_inequalities(
+      vec![
+        Inequality::new(CellPosition::new(0, 0), CellPosition::new(1, 0)),
+        Inequality::new(CellPosition::new(1, 0), CellPosition::new(1, 1)),
+        Inequality::new(CellPosition::new(5, 5), CellPosition::new(5, 6)),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  // Only the first chain is reported, (5, 5) and (5, 6) will be handled later
+  let steps = InequalityCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  let step = &steps[0];
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(step.values, vec![2, 4, 5, 6, 7, 8, 9]);
+  assert_eq!(step.areas, vec![ Area::Inequality(0), Area::Inequality(1) ]);
+
+  let step = &steps[1];
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(1, 0) ]);
+  assert_eq!(step.values, vec![1, 4, 5, 6, 7, 8, 9]);
+  assert_eq!(step.areas, vec![ Area::Inequality(0), Area::Inequality(1) ]);
+}
@@ -9,10 +9,12 @@ fn check_killer45_row() {
         KillerCage {
           sum: Some(4),
           region: Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(15),
           region: Region(vec![ CellPosition::new(0, 2), CellPosition::new(0, 3), CellPosition::new(0, 4) ]),
+          ..Default::default()
         },
       ]
     );
@@ -45,10 +47,12 @@ fn check_killer45_region() {
             CellPosition::new(3, 3), CellPosition::new(3, 4), CellPosition::new(3, 5),
             CellPosition::new(4, 3), CellPosition::new(5, 3),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(11),
           region: Region(vec![ CellPosition::new(4, 5), CellPosition::new(5, 5) ]),
+          ..Default::default()
         },
       ]
     );
@@ -81,6 +85,7 @@ fn check_killer45_col_with_fixed_digits() {
         KillerCage {
           sum: Some(12),
           region: Region(vec![ CellPosition::new(1, 1), CellPosition::new(2, 1), CellPosition::new(3, 1) ]),
+          ..Default::default()
         },
       ]
     );
@@ -122,16 +127,19 @@ fn check_killer45_col_partial_cages() {
             CellPosition::new(1, 2), CellPosition::new(2, 2), CellPosition::new(2, 3),
             CellPosition::new(3, 3), CellPosition::new(3, 4), CellPosition::new(4, 4),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(13),
           region: Region(vec![
             CellPosition::new(4, 2), CellPosition::new(4, 3), CellPosition::new(5, 3),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(12),
           region: Region(vec![ CellPosition::new(0, 3), CellPosition::new(0, 4), CellPosition::new(0, 5) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(28),
@@ -139,6 +147,7 @@ fn check_killer45_col_partial_cages() {
             CellPosition::new(6, 3), CellPosition::new(6, 2), CellPosition::new(7, 2),
             CellPosition::new(7, 3), CellPosition::new(8, 3),
           ]),
+          ..Default::default()
         },
       ]
     );
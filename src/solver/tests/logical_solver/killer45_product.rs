@@ -0,0 +1,37 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, killer45_product::Killer45Product, technique::Technique}}, types::{Area, CellPosition, KillerCage, Region, Rule, SudokuConstraints}};
+
+#[test]
+fn check_killer45_product_row() {
+  let constraints = SudokuConstraints::new(6)
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: None,
+          product: Some(6),
+          region: Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(20),
+          region: Region(vec![ CellPosition::new(0, 2), CellPosition::new(0, 3), CellPosition::new(0, 4) ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&mut Candidates.run(&solver).first().unwrap());
+  let steps = Killer45Product.run(&solver);
+  assert!(!steps.is_empty());
+  let step = steps.into_iter().next().unwrap();
+
+  assert_eq!(step.rule, Rule::Killer45Product);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(0, 5) ]);
+  assert_eq!(step.values, vec![ 1, 2, 3, 4, 5 ]);
+  assert_eq!(step.areas, vec![ Area::Row(0) ]);
+  solver.apply_rule(&step);
+  let final_candidates = &solver.candidates[0][5];
+  assert_eq!(final_candidates.len(), 1);
+  assert!(final_candidates.contains(&6));
+}
@@ -9,6 +9,7 @@ fn check_killer_candidates_single_unfixed() {
         KillerCage {
           sum: Some(7),
           region: Region(vec![ CellPosition::new(8, 8) ]),
+          ..Default::default()
         },
       ]
     );
@@ -39,6 +40,7 @@ fn check_killer_candidates_single_fixed() {
         KillerCage {
           sum: Some(5),
           region: Region(vec![ CellPosition::new(0, 0) ]),
+          ..Default::default()
         },
       ]
     );
@@ -57,6 +59,7 @@ fn check_killer_candidates_pair_1() {
         KillerCage {
           sum: Some(4),
           region: Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]),
+          ..Default::default()
         },
       ]
     );
@@ -94,6 +97,7 @@ fn check_killer_candidates_pair_2() {
         KillerCage {
           sum: Some(4),
           region: Region(vec![ CellPosition::new(8, 0), CellPosition::new(8, 1) ]),
+          ..Default::default()
         },
       ]
     );
@@ -122,3 +126,59 @@ fn check_killer_candidates_pair_2() {
   assert_eq!(step.values.iter().copied().collect_vec(), vec![ 2, 3, 4 ]);
   assert_eq!(step.areas, vec![ Area::KillerCage(0) ]);
 }
+
+#[test]
+fn check_killer_candidates_product() {
+  let constraints = SudokuConstraints::new(9)
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: None,
+          product: Some(12),
+          region: Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&mut Candidates.run(&solver).first().unwrap());
+  assert_eq!(solver.candidates[0][0].iter().copied().sorted().collect_vec(), vec![ 1, 2, 3, 4, 6 ]);
+  let steps = KillerCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+  for step in &steps {
+    assert_eq!(step.rule, Rule::KillerCandidates);
+    assert_eq!(step.values, vec![ 1 ]);
+    assert_eq!(step.areas, vec![ Area::KillerCage(0) ]);
+  }
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(steps[1].affected_cells, vec![ CellPosition::new(0, 1) ]);
+}
+
+#[test]
+fn check_killer_candidates_non_unique() {
+  let constraints = SudokuConstraints::new(9)
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: Some(2),
+          unique: false,
+          region: Region(vec![ CellPosition::new(2, 2), CellPosition::new(3, 3) ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&mut Candidates.run(&solver).first().unwrap());
+  let steps = KillerCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+  for step in &steps {
+    assert_eq!(step.rule, Rule::KillerCandidates);
+    assert_eq!(step.values, vec![ 2, 3, 4, 5, 6, 7, 8, 9 ]);
+    assert_eq!(step.areas, vec![ Area::KillerCage(0) ]);
+  }
+  solver.apply_rules(&steps);
+  assert_eq!(solver.candidates[2][2].iter().copied().collect_vec(), vec![ 1 ]);
+  assert_eq!(solver.candidates[3][3].iter().copied().collect_vec(), vec![ 1 ]);
+}
@@ -0,0 +1,86 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, little_killer_candidates::LittleKillerCandidates, technique::Technique}}, types::{Area, CellDirection, CellPosition, Rule, SudokuConstraints, LittleKiller}};
+
+#[test]
+fn check_little_killer_candidates_unique_digits() {
+  let constraints = SudokuConstraints::new(9)
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 6, cell: CellPosition::new(0, 6), direction: CellDirection { row: 1, col: 1 } },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = LittleKillerCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  // All cells are in the same box, so they must be 1, 2 and 3
+  for (step, cell) in steps.iter().zip([ CellPosition::new(0, 6), CellPosition::new(1, 7), CellPosition::new(2, 8) ]) {
+    assert_eq!(step.rule, Rule::LittleKillerCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, vec![4, 5, 6, 7, 8, 9]);
+    assert_eq!(step.areas, vec![ Area::LittleKiller(0) ]);
+  }
+}
+
+#[test]
+fn check_little_killer_candidates_repeated_digits() {
+  let constraints = SudokuConstraints::new(9)
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 5, cell: CellPosition::new(3, 0), direction: CellDirection { row: -1, col: 1 } },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = LittleKillerCandidates.run(&solver);
+  assert_eq!(steps.len(), 4);
+
+  // The ends don't see any other cell on the diagonal, so they can repeat the 1
+  let expected = [
+    (CellPosition::new(3, 0), vec![2, 3, 4, 5, 6, 7, 8, 9]),
+    (CellPosition::new(2, 1), vec![3, 4, 5, 6, 7, 8, 9]),
+    (CellPosition::new(1, 2), vec![3, 4, 5, 6, 7, 8, 9]),
+    (CellPosition::new(0, 3), vec![2, 3, 4, 5, 6, 7, 8, 9]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::LittleKillerCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+    assert_eq!(step.areas, vec![ Area::LittleKiller(0) ]);
+  }
+}
+
+#[test]
+fn check_little_killer_candidates_long_diagonal_sum_bounds() {
+  let constraints = SudokuConstraints::new(9)
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 8, cell: CellPosition::new(0, 0), direction: CellDirection { row: 1, col: 1 } },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  // 9 cells can't add up to less than 9, so the diagonal can't be filled
+  let steps = LittleKillerCandidates.run(&solver);
+  assert_eq!(steps.len(), 9);
+  for (index, step) in steps.iter().enumerate() {
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(index, index) ]);
+    assert_eq!(step.values, (1..=9).collect::<Vec<u32>>());
+    assert_eq!(step.areas, vec![ Area::LittleKiller(0) ]);
+  }
+}
+
+#[test]
+#[should_panic(expected = "little_killers")]
+fn check_little_killer_invalid_direction() {
+  let constraints = SudokuConstraints::new(9)
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 8, cell: CellPosition::new(0, 0), direction: CellDirection { row: 0, col: 0 } },
+      ]
+    );
+  Solver::new(constraints);
+}
@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, min_max_candidates::MinMaxCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_min_max_candidates_empty_grid() {
+  let constraints = SudokuConstraints::new(9)
+    .with_minimum_cells(vec![ CellPosition::new(4, 4) ])
+    .with_maximum_cells(vec![ CellPosition::new(0, 0) ]);
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&Candidates.run(&solver)[0]);
+  let steps = MinMaxCandidates.run(&solver);
+  assert_eq!(steps.len(), 5);
+  assert!(steps.iter().all(|step| step.rule == Rule::MinMaxCandidates));
+  assert!(steps.iter().all(|step| step.areas == vec![ Area::Cell(4, 4) ]));
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(4, 4) ]);
+  assert_eq!(steps[0].values, vec![ 9 ]);
+  assert!(steps[1..].iter().all(|step| step.values == vec![ 1 ]));
+}
+
+#[test]
+fn check_min_max_candidates_fixed_neighbour() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(vec![ FixedNumber::new(0, 1, 7) ])
+    .with_maximum_cells(vec![ CellPosition::new(0, 0), CellPosition::new(8, 8) ]);
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&Candidates.run(&solver)[0]);
+  assert_eq!(solver.candidates[0][0], HashSet::from([ 8, 9 ]));
+  let steps = MinMaxCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(1, 0) ]);
+  assert_eq!(steps[0].values, vec![ 9 ]);
+  assert_eq!(steps[0].areas, vec![ Area::Cell(0, 0) ]);
+}
@@ -0,0 +1,34 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, modular_line_candidates::ModularLineCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, ModularLine, Rule, SudokuConstraints}};
+
+#[test]
+fn check_modular_line_candidates() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 3)]
+    )
+    .with_modular_lines(
+      vec![
+        ModularLine(vec![
+          CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2), CellPosition::new(1, 1),
+        ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = ModularLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  // The 4th cell must be a multiple of 3 like the 1st one
+  let expected = [
+    (CellPosition::new(0, 1), vec![6, 9]),
+    (CellPosition::new(0, 2), vec![6, 9]),
+    (CellPosition::new(1, 1), vec![1, 2, 4, 5, 7, 8]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::ModularLineCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+    assert_eq!(step.areas, vec![ Area::ModularLine(0) ]);
+  }
+}
@@ -0,0 +1,49 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, nabner_line_candidates::NabnerLineCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, NabnerLine, Rule, SudokuConstraints}};
+
+#[test]
+fn check_nabner_line_candidates() {
+  let constraints = SudokuConstraints::new(4)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 1, 1), FixedNumber::new(0, 2, 2), FixedNumber::new(0, 3, 3)]
+    )
+    .with_nabner_lines(
+      vec![
+        NabnerLine(vec![ CellPosition::new(0, 0), CellPosition::new(1, 1) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = NabnerLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+
+  // The first cell can only be 4
+  let step = &steps[0];
+  assert_eq!(step.rule, Rule::NabnerLineCandidates);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(1, 1) ]);
+  assert_eq!(step.values, vec![3, 4]);
+  assert_eq!(step.areas, vec![ Area::NabnerLine(0) ]);
+}
+
+#[test]
+fn check_nabner_line_candidates_long_line() {
+  let cells = vec![
+    CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2),
+    CellPosition::new(1, 2), CellPosition::new(1, 1),
+  ];
+  let constraints = SudokuConstraints::new(9)
+    .with_nabner_lines(
+      vec![ NabnerLine(cells.clone()) ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = NabnerLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 5);
+
+  // 5 non-consecutive digits must be 1, 3, 5, 7 and 9
+  for (step, cell) in steps.iter().zip(cells) {
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, vec![2, 4, 6, 8]);
+  }
+}
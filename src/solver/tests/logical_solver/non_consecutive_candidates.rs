@@ -0,0 +1,56 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, non_consecutive_candidates::NonConsecutiveCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, Rule, SudokuConstraints}};
+
+#[test]
+fn check_non_consecutive_candidates_neighbour_values() {
+  let constraints = SudokuConstraints::new(4)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 2, 2), FixedNumber::new(2, 0, 4) ]
+    )
+    .with_non_consecutive();
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = NonConsecutiveCandidates.run(&solver);
+  assert_eq!(steps.len(), 7);
+
+  // (0, 1) can only be 4, so (0, 0) can't be 3
+  let step = &steps[0];
+  assert_eq!(step.rule, Rule::NonConsecutiveCandidates);
+  assert_eq!(step.cells, vec![ CellPosition::new(0, 1) ]);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(step.values, vec![ 3 ]);
+  assert_eq!(step.areas, vec![ Area::Cell(0, 1) ]);
+  solver.apply_rule(step);
+  assert!(!solver.candidates[0][0].contains(&3));
+
+  // (0, 0) is 1 or 3, so (1, 0) can't be 2
+  let step = &steps[1];
+  assert_eq!(step.rule, Rule::NonConsecutiveCandidates);
+  assert_eq!(step.cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(1, 0) ]);
+  assert_eq!(step.values, vec![ 2 ]);
+}
+
+#[test]
+fn check_non_consecutive_candidates_diagonal() {
+  let constraints = SudokuConstraints::new(4)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 0, 2) ]
+    )
+    .with_diagonal_non_consecutive();
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  assert_eq!(solver.candidates[1][1].iter().copied().collect::<Vec<u32>>(), vec![ 4 ]);
+
+  // (1, 1) can only be 4, so its diagonal neighbours can't be 3
+  let steps = NonConsecutiveCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  let affected_cells = [ CellPosition::new(0, 2), CellPosition::new(2, 0), CellPosition::new(2, 2) ];
+  for (step, cell) in steps.iter().zip(affected_cells) {
+    assert_eq!(step.rule, Rule::NonConsecutiveCandidates);
+    assert_eq!(step.cells, vec![ CellPosition::new(1, 1) ]);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, vec![ 3 ]);
+  }
+}
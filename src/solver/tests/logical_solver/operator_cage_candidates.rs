@@ -0,0 +1,72 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, operator_cage_candidates::OperatorCageCandidates, technique::Technique}}, types::{Area, CageOperator, CellPosition, OperatorCage, Region, Rule, SudokuConstraints}};
+
+#[test]
+fn check_operator_cage_candidates_subtract() {
+  let constraints = SudokuConstraints::new(9)
+    .with_operator_cages(
+      vec![
+        OperatorCage::new(
+          CageOperator::Subtract,
+          7,
+          Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]),
+        ),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&Candidates.run(&solver)[0]);
+  let steps = OperatorCageCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+  for step in &steps {
+    assert_eq!(step.rule, Rule::OperatorCageCandidates);
+    assert_eq!(step.values, vec![ 3, 4, 5, 6, 7 ]);
+    assert_eq!(step.areas, vec![ Area::OperatorCage(0) ]);
+  }
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(steps[1].affected_cells, vec![ CellPosition::new(0, 1) ]);
+}
+
+#[test]
+fn check_operator_cage_candidates_divide() {
+  let constraints = SudokuConstraints::new(9)
+    .with_operator_cages(
+      vec![
+        OperatorCage::new(
+          CageOperator::Divide,
+          4,
+          Region(vec![ CellPosition::new(0, 0), CellPosition::new(1, 0) ]),
+        ),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&Candidates.run(&solver)[0]);
+  let steps = OperatorCageCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+  for step in &steps {
+    assert_eq!(step.values, vec![ 3, 5, 6, 7, 9 ]);
+  }
+}
+
+#[test]
+fn check_operator_cage_candidates_add_with_repeats() {
+  let constraints = SudokuConstraints::new(9)
+    .with_operator_cages(
+      vec![
+        OperatorCage::new(
+          CageOperator::Add,
+          2,
+          Region(vec![ CellPosition::new(2, 2), CellPosition::new(3, 3) ]),
+        ),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&Candidates.run(&solver)[0]);
+  let steps = OperatorCageCandidates.run(&solver);
+  solver.apply_rules(&steps);
+  assert_eq!(solver.candidates[2][2].len(), 1);
+  assert!(solver.candidates[2][2].contains(&1));
+  assert_eq!(solver.candidates[3][3].len(), 1);
+  assert!(solver.candidates[3][3].contains(&1));
+}
@@ -0,0 +1,63 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, parity_line_candidates::ParityLineCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, ParityLine, Rule, SudokuConstraints}};
+
+#[test]
+fn check_parity_line_candidates_known_parity() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(3, 0, 1), FixedNumber::new(4, 0, 3), FixedNumber::new(5, 0, 5),
+        FixedNumber::new(6, 0, 7), FixedNumber::new(7, 0, 9),
+      ]
+    )
+    .with_parity_lines(
+      vec![
+        ParityLine(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(1, 1) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = ParityLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  // The column leaves only even digits for the first cell
+  let expected = [
+    (CellPosition::new(0, 1), vec![2, 4, 6, 8]),
+    (CellPosition::new(1, 1), vec![1, 3, 5, 7, 9]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::ParityLineCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+    assert_eq!(step.areas, vec![ Area::ParityLine(0) ]);
+  }
+}
+
+#[test]
+fn check_parity_line_candidates_region_parity_counts() {
+  let cells = vec![
+    CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2),
+    CellPosition::new(1, 2), CellPosition::new(1, 1), CellPosition::new(1, 0),
+    CellPosition::new(2, 0), CellPosition::new(2, 1), CellPosition::new(2, 2),
+  ];
+  let constraints = SudokuConstraints::new(9)
+    .with_parity_lines(
+      vec![ ParityLine(cells.clone()) ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = ParityLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 9);
+
+  // There are only 4 even digits for the 5 cells at even positions
+  for (index, step) in steps.iter().enumerate() {
+    assert_eq!(step.affected_cells, vec![ cells[index] ]);
+    if index % 2 == 0 {
+      assert_eq!(step.values, vec![2, 4, 6, 8]);
+    } else {
+      assert_eq!(step.values, vec![1, 3, 5, 7, 9]);
+    }
+    assert_eq!(step.areas, vec![ Area::ParityLine(0), Area::Region(0) ]);
+  }
+}
@@ -4,6 +4,8 @@ mod classic_9x9;
 mod thermo_4x4;
 mod thermo_6x6;
 mod thermo_9x9;
+mod slow_thermo_9x9;
+mod branching_thermo_9x9;
 mod diagonal_4x4;
 mod diagonal_9x9;
 mod anti_knight_4x4;
@@ -12,6 +14,8 @@ mod anti_knight_9x9;
 mod anti_king_6x6;
 mod killer_6x6;
 mod killer_9x9;
+mod killer_product_9x9;
+mod killer_non_unique_9x9;
 mod kropki_4x4;
 mod kropki_6x6;
 mod kropki_9x9;
@@ -29,3 +33,27 @@ mod renban_6x6;
 mod renban_9x9;
 mod palindrome_4x4;
 mod palindrome_9x9;
+mod whispers_9x9;
+mod difference_line_9x9;
+mod between_line_9x9;
+mod region_sum_line_9x9;
+mod entropic_line_6x6;
+mod entropic_line_9x9;
+mod parity_line_9x9;
+mod zipper_9x9;
+mod modular_line_9x9;
+mod nabner_line_9x9;
+mod little_killer_9x9;
+mod sandwich_9x9;
+mod x_sum_9x9;
+mod skyscraper_9x9;
+mod xv_9x9;
+mod quadruple_9x9;
+mod non_consecutive_9x9;
+mod disjoint_groups_6x6;
+mod disjoint_groups_9x9;
+mod inequality_9x9;
+mod clone_9x9;
+mod operator_cage_9x9;
+mod fortress_9x9;
+mod min_max_9x9;
@@ -0,0 +1,85 @@
+use crate::{solver::Solver, types::{BranchingThermo, CellPosition, FixedNumber, Grid, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_branching_thermo_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 2, 1),
+        FixedNumber::new(0, 4, 2),
+        FixedNumber::new(0, 5, 5),
+        FixedNumber::new(0, 7, 6),
+        FixedNumber::new(2, 7, 7),
+        FixedNumber::new(3, 2, 3),
+        FixedNumber::new(4, 4, 1),
+        FixedNumber::new(4, 5, 6),
+        FixedNumber::new(5, 1, 8),
+        FixedNumber::new(5, 4, 7),
+        FixedNumber::new(7, 6, 8),
+        FixedNumber::new(8, 0, 2),
+        FixedNumber::new(8, 7, 1),
+      ]
+    )
+    .with_branching_thermos(
+      vec![
+        BranchingThermo::new(
+          CellPosition::new(0, 1),
+          vec![
+            vec![ CellPosition::new(1, 0), CellPosition::new(2, 0), CellPosition::new(1, 1), CellPosition::new(1, 2) ],
+            vec![ CellPosition::new(0, 0) ],
+          ],
+        ),
+        BranchingThermo::new(
+          CellPosition::new(2, 6),
+          vec![
+            vec![ CellPosition::new(3, 7), CellPosition::new(4, 8), CellPosition::new(4, 7), CellPosition::new(3, 8) ],
+            vec![ CellPosition::new(1, 7), CellPosition::new(1, 6) ],
+            vec![ CellPosition::new(3, 5), CellPosition::new(3, 6), CellPosition::new(2, 5) ],
+          ],
+        ),
+        BranchingThermo::new(
+          CellPosition::new(7, 4),
+          vec![
+            vec![ CellPosition::new(8, 5), CellPosition::new(8, 4) ],
+            vec![ CellPosition::new(7, 3), CellPosition::new(8, 2), CellPosition::new(7, 1) ],
+            vec![ CellPosition::new(6, 5) ],
+          ],
+        ),
+        BranchingThermo::new(
+          CellPosition::new(4, 3),
+          vec![
+            vec![ CellPosition::new(4, 2), CellPosition::new(5, 1), CellPosition::new(4, 0) ],
+            vec![ CellPosition::new(5, 4), CellPosition::new(5, 3) ],
+          ],
+        ),
+        BranchingThermo::new(
+          CellPosition::new(5, 2),
+          vec![
+            vec![ CellPosition::new(4, 1), CellPosition::new(3, 1) ],
+            vec![ CellPosition::new(6, 2) ],
+          ],
+        ),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::ThermoCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,59 @@
+use crate::{solver::Solver, types::{CellPosition, CloneGroup, FixedNumber, Grid, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_clone_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 2, 1),
+        FixedNumber::new(1, 0, 4),
+        FixedNumber::new(1, 6, 5),
+        FixedNumber::new(1, 8, 8),
+        FixedNumber::new(3, 5, 2),
+        FixedNumber::new(3, 8, 9),
+        FixedNumber::new(5, 7, 3),
+        FixedNumber::new(6, 8, 7),
+        FixedNumber::new(7, 0, 7),
+        FixedNumber::new(7, 2, 4),
+        FixedNumber::new(7, 5, 1),
+        FixedNumber::new(8, 1, 5),
+        FixedNumber::new(8, 4, 9),
+        FixedNumber::new(8, 8, 6),
+      ]
+    )
+    .with_clones(
+      vec![
+        CloneGroup(vec![
+          vec![ CellPosition::new(0, 4), CellPosition::new(0, 5), CellPosition::new(1, 4), CellPosition::new(1, 5) ],
+          vec![ CellPosition::new(2, 1), CellPosition::new(2, 2), CellPosition::new(3, 1), CellPosition::new(3, 2) ],
+          vec![ CellPosition::new(6, 3), CellPosition::new(6, 4), CellPosition::new(7, 3), CellPosition::new(7, 4) ],
+        ]),
+        CloneGroup(vec![
+          vec![ CellPosition::new(2, 3), CellPosition::new(2, 4), CellPosition::new(2, 5), CellPosition::new(3, 4) ],
+          vec![ CellPosition::new(6, 5), CellPosition::new(6, 6), CellPosition::new(6, 7), CellPosition::new(7, 6) ],
+        ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::CloneValues));
+  assert!(rules.contains(&Rule::CloneCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,33 @@
+use crate::{solver::Solver, types::{FixedNumber, Grid, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_disjoint_groups_6x6_solve() {
+  let constraints = SudokuConstraints::new(6)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 2, 6),
+        FixedNumber::new(1, 0, 2),
+        FixedNumber::new(3, 0, 1),
+        FixedNumber::new(3, 2, 3),
+        FixedNumber::new(4, 5, 4),
+        FixedNumber::new(5, 2, 5),
+        FixedNumber::new(5, 4, 6),
+      ]
+    )
+    .with_disjoint_groups();
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 5, 1, 6, 4, 2, 3 ],
+      vec![ 2, 3, 4, 5, 1, 6 ],
+      vec![ 6, 4, 2, 1, 3, 5 ],
+      vec![ 1, 5, 3, 6, 4, 2 ],
+      vec![ 3, 6, 1, 2, 5, 4 ],
+      vec![ 4, 2, 5, 3, 6, 1 ],
+    ])
+  );
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,48 @@
+use crate::{solver::Solver, types::{FixedNumber, Grid, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_disjoint_groups_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 1, 3),
+        FixedNumber::new(0, 5, 5),
+        FixedNumber::new(0, 7, 6),
+        FixedNumber::new(1, 2, 9),
+        FixedNumber::new(1, 3, 1),
+        FixedNumber::new(2, 7, 7),
+        FixedNumber::new(3, 4, 1),
+        FixedNumber::new(3, 7, 9),
+        FixedNumber::new(3, 8, 2),
+        FixedNumber::new(4, 0, 5),
+        FixedNumber::new(4, 2, 4),
+        FixedNumber::new(4, 7, 3),
+        FixedNumber::new(5, 0, 6),
+        FixedNumber::new(5, 4, 5),
+        FixedNumber::new(6, 1, 4),
+        FixedNumber::new(6, 3, 2),
+        FixedNumber::new(7, 2, 8),
+        FixedNumber::new(7, 6, 2),
+        FixedNumber::new(8, 5, 8),
+      ]
+    )
+    .with_disjoint_groups();
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 4, 6, 9 ],
+      vec![ 4, 7, 9, 1, 8, 6, 3, 2, 5 ],
+      vec![ 2, 6, 5, 4, 3, 9, 8, 7, 1 ],
+      vec![ 3, 8, 7, 6, 1, 4, 5, 9, 2 ],
+      vec![ 5, 1, 4, 8, 9, 2, 6, 3, 7 ],
+      vec![ 6, 9, 2, 3, 5, 7, 1, 8, 4 ],
+      vec![ 1, 4, 6, 2, 7, 3, 9, 5, 8 ],
+      vec![ 7, 5, 8, 9, 6, 1, 2, 4, 3 ],
+      vec![ 9, 2, 3, 5, 4, 8, 7, 1, 6 ],
+    ])
+  );
+  insta::assert_yaml_snapshot!(result.steps);
+}
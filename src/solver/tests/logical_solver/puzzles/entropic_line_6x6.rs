@@ -0,0 +1,62 @@
+use crate::{solver::Solver, types::{CellPosition, EntropicLine, FixedNumber, Grid, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_entropic_line_6x6_1_solve() {
+  let constraints = SudokuConstraints::new(6)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(1, 2, 5),
+        FixedNumber::new(1, 3, 3),
+        FixedNumber::new(2, 1, 1),
+        FixedNumber::new(2, 5, 6),
+        FixedNumber::new(3, 4, 1),
+        FixedNumber::new(5, 0, 2),
+        FixedNumber::new(5, 5, 3),
+      ]
+    )
+    .with_entropic_lines(
+      vec![
+        EntropicLine(vec![
+          CellPosition::new(1, 4),
+          CellPosition::new(1, 3),
+          CellPosition::new(0, 3),
+        ]),
+        EntropicLine(vec![
+          CellPosition::new(3, 3),
+          CellPosition::new(4, 2),
+          CellPosition::new(4, 1),
+          CellPosition::new(5, 0),
+        ]),
+        EntropicLine(vec![
+          CellPosition::new(4, 0),
+          CellPosition::new(5, 1),
+          CellPosition::new(5, 2),
+          CellPosition::new(5, 3),
+          CellPosition::new(4, 3),
+        ]),
+        EntropicLine(vec![
+          CellPosition::new(2, 0),
+          CellPosition::new(1, 0),
+          CellPosition::new(0, 0),
+        ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 6, 2, 3, 1, 4, 5 ],
+      vec![ 1, 4, 5, 3, 6, 2 ],
+      vec![ 4, 1, 2, 5, 3, 6 ],
+      vec![ 5, 3, 6, 2, 1, 4 ],
+      vec![ 3, 5, 4, 6, 2, 1 ],
+      vec![ 2, 6, 1, 4, 5, 3 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::EntropicLineCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,56 @@
+use crate::{solver::Solver, types::{CellPosition, FixedNumber, Grid, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_fortress_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 4, 2),
+        FixedNumber::new(0, 7, 6),
+        FixedNumber::new(2, 6, 1),
+        FixedNumber::new(3, 0, 1),
+        FixedNumber::new(4, 0, 9),
+        FixedNumber::new(4, 3, 3),
+        FixedNumber::new(5, 2, 2),
+        FixedNumber::new(5, 4, 7),
+        FixedNumber::new(6, 6, 4),
+        FixedNumber::new(6, 8, 7),
+        FixedNumber::new(7, 3, 6),
+        FixedNumber::new(7, 5, 1),
+        FixedNumber::new(7, 8, 2),
+      ]
+    )
+    .with_fortress_cells(
+      vec![
+        CellPosition::new(0, 0), CellPosition::new(0, 3), CellPosition::new(0, 6), CellPosition::new(1, 2),
+        CellPosition::new(1, 4), CellPosition::new(1, 8), CellPosition::new(2, 0), CellPosition::new(2, 3),
+        CellPosition::new(2, 5), CellPosition::new(2, 7), CellPosition::new(3, 1), CellPosition::new(3, 4),
+        CellPosition::new(3, 6), CellPosition::new(3, 8), CellPosition::new(4, 0), CellPosition::new(4, 2),
+        CellPosition::new(4, 5), CellPosition::new(4, 7), CellPosition::new(5, 1), CellPosition::new(5, 3),
+        CellPosition::new(5, 6), CellPosition::new(6, 2), CellPosition::new(6, 5), CellPosition::new(6, 7),
+        CellPosition::new(7, 1), CellPosition::new(7, 3), CellPosition::new(7, 6), CellPosition::new(8, 2),
+        CellPosition::new(8, 4), CellPosition::new(8, 8),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::FortressCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,82 @@
+use crate::{solver::Solver, types::{CellPosition, FixedNumber, Grid, Inequality, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_inequality_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 5, 5),
+        FixedNumber::new(0, 7, 6),
+        FixedNumber::new(1, 0, 4),
+        FixedNumber::new(2, 0, 6),
+        FixedNumber::new(2, 1, 2),
+        FixedNumber::new(2, 6, 1),
+        FixedNumber::new(3, 1, 6),
+        FixedNumber::new(3, 7, 4),
+        FixedNumber::new(4, 2, 7),
+        FixedNumber::new(4, 6, 2),
+        FixedNumber::new(5, 3, 9),
+        FixedNumber::new(5, 4, 7),
+        FixedNumber::new(6, 0, 3),
+        FixedNumber::new(6, 1, 1),
+        FixedNumber::new(6, 8, 7),
+        FixedNumber::new(7, 3, 6),
+        FixedNumber::new(7, 7, 5),
+        FixedNumber::new(8, 5, 7),
+      ]
+    )
+    .with_inequalities(
+      vec![
+        Inequality::new(CellPosition::new(0, 1), CellPosition::new(1, 1)),
+        Inequality::new(CellPosition::new(0, 4), CellPosition::new(0, 5)),
+        Inequality::new(CellPosition::new(0, 8), CellPosition::new(0, 7)),
+        Inequality::new(CellPosition::new(1, 0), CellPosition::new(1, 1)),
+        Inequality::new(CellPosition::new(1, 3), CellPosition::new(1, 4)),
+        Inequality::new(CellPosition::new(1, 7), CellPosition::new(1, 6)),
+        Inequality::new(CellPosition::new(2, 4), CellPosition::new(2, 3)),
+        Inequality::new(CellPosition::new(2, 8), CellPosition::new(2, 7)),
+        Inequality::new(CellPosition::new(3, 0), CellPosition::new(4, 0)),
+        Inequality::new(CellPosition::new(3, 2), CellPosition::new(4, 2)),
+        Inequality::new(CellPosition::new(3, 5), CellPosition::new(3, 4)),
+        Inequality::new(CellPosition::new(4, 4), CellPosition::new(3, 4)),
+        Inequality::new(CellPosition::new(3, 7), CellPosition::new(3, 6)),
+        Inequality::new(CellPosition::new(3, 7), CellPosition::new(3, 8)),
+        Inequality::new(CellPosition::new(5, 0), CellPosition::new(4, 0)),
+        Inequality::new(CellPosition::new(4, 1), CellPosition::new(4, 2)),
+        Inequality::new(CellPosition::new(4, 4), CellPosition::new(4, 3)),
+        Inequality::new(CellPosition::new(5, 2), CellPosition::new(5, 1)),
+        Inequality::new(CellPosition::new(5, 5), CellPosition::new(5, 4)),
+        Inequality::new(CellPosition::new(5, 7), CellPosition::new(5, 6)),
+        Inequality::new(CellPosition::new(5, 8), CellPosition::new(5, 7)),
+        Inequality::new(CellPosition::new(6, 1), CellPosition::new(7, 1)),
+        Inequality::new(CellPosition::new(6, 6), CellPosition::new(7, 6)),
+        Inequality::new(CellPosition::new(7, 7), CellPosition::new(6, 7)),
+        Inequality::new(CellPosition::new(7, 7), CellPosition::new(7, 6)),
+        Inequality::new(CellPosition::new(8, 0), CellPosition::new(8, 1)),
+        Inequality::new(CellPosition::new(8, 3), CellPosition::new(8, 4)),
+        Inequality::new(CellPosition::new(8, 5), CellPosition::new(8, 4)),
+        Inequality::new(CellPosition::new(8, 7), CellPosition::new(8, 8)),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::InequalityCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -19,6 +19,7 @@ fn check_killer_6x6_1_solve() {
           region: Region(vec![
             CellPosition::new(0, 0), CellPosition::new(1, 0),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(15),
@@ -27,44 +28,52 @@ fn check_killer_6x6_1_solve() {
             CellPosition::new(0, 3), CellPosition::new(0, 4),
             CellPosition::new(1, 3),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(5),
           region: Region(vec![ CellPosition::new(1, 1) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(5),
           region: Region(vec![
             CellPosition::new(1, 4), CellPosition::new(1, 5),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(5),
           region: Region(vec![
             CellPosition::new(2, 0), CellPosition::new(2, 1),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(5),
           region: Region(vec![
             CellPosition::new(2, 2), CellPosition::new(3, 2),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(5),
           region: Region(vec![ CellPosition::new(3, 0) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(15),
           region: Region(vec![
             CellPosition::new(2, 3), CellPosition::new(2, 4), CellPosition::new(2, 5),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(5),
           region: Region(vec![
             CellPosition::new(3, 3), CellPosition::new(3, 4),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(15),
@@ -72,10 +81,12 @@ fn check_killer_6x6_1_solve() {
             CellPosition::new(4, 0), CellPosition::new(4, 1), CellPosition::new(4, 2),
             CellPosition::new(5, 0),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(5),
           region: Region(vec![ CellPosition::new(4, 5) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(15),
@@ -83,6 +94,7 @@ fn check_killer_6x6_1_solve() {
             CellPosition::new(5, 1), CellPosition::new(5, 2), CellPosition::new(5, 3),
             CellPosition::new(5, 4), CellPosition::new(5, 5),
           ]),
+          ..Default::default()
         },
       ]
     );
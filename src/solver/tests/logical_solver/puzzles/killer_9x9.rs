@@ -12,36 +12,42 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(0, 0), CellPosition::new(0, 1),
             CellPosition::new(1, 0), CellPosition::new(1, 1),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(9),
           region: Region(vec![
             CellPosition::new(0, 2), CellPosition::new(0, 3),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(22),
           region: Region(vec![
             CellPosition::new(1, 2), CellPosition::new(2, 2), CellPosition::new(2, 1),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(17),
           region: Region(vec![
             CellPosition::new(0, 4), CellPosition::new(1, 4), CellPosition::new(2, 4),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(9),
           region: Region(vec![
             CellPosition::new(0, 5), CellPosition::new(0, 6),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(18),
           region: Region(vec![
             CellPosition::new(1, 6), CellPosition::new(2, 6), CellPosition::new(2, 7),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(20),
@@ -49,12 +55,14 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(0, 7), CellPosition::new(0, 8),
             CellPosition::new(1, 7), CellPosition::new(1, 8),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(8),
           region: Region(vec![
             CellPosition::new(2, 0), CellPosition::new(3, 0),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(16),
@@ -62,6 +70,7 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(1, 3), CellPosition::new(2, 3), CellPosition::new(3, 3),
             CellPosition::new(3, 2), CellPosition::new(3, 1),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(27),
@@ -69,24 +78,28 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(1, 5), CellPosition::new(2, 5), CellPosition::new(3, 5),
             CellPosition::new(3, 6), CellPosition::new(3, 7),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(11),
           region: Region(vec![
             CellPosition::new(2, 8), CellPosition::new(3, 8),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(12),
           region: Region(vec![
             CellPosition::new(4, 0), CellPosition::new(4, 1), CellPosition::new(4, 2),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(22),
           region: Region(vec![
             CellPosition::new(4, 6), CellPosition::new(4, 7), CellPosition::new(4, 8),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(15),
@@ -94,12 +107,14 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(3, 4), CellPosition::new(4, 3), CellPosition::new(4, 4),
             CellPosition::new(4, 5), CellPosition::new(5, 4),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(8),
           region: Region(vec![
             CellPosition::new(5, 0), CellPosition::new(6, 0),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(30),
@@ -107,6 +122,7 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(5, 1), CellPosition::new(5, 2), CellPosition::new(5, 3),
             CellPosition::new(6, 3), CellPosition::new(7, 3),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(17),
@@ -114,18 +130,21 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(5, 7), CellPosition::new(5, 6), CellPosition::new(5, 5),
             CellPosition::new(6, 5), CellPosition::new(7, 5),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(11),
           region: Region(vec![
             CellPosition::new(5, 8), CellPosition::new(6, 8),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(11),
           region: Region(vec![
             CellPosition::new(6, 1), CellPosition::new(6, 2), CellPosition::new(7, 2),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(24),
@@ -133,30 +152,35 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(7, 0), CellPosition::new(7, 1),
             CellPosition::new(8, 0), CellPosition::new(8, 1),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(16),
           region: Region(vec![
             CellPosition::new(8, 2), CellPosition::new(8, 3),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(19),
           region: Region(vec![
             CellPosition::new(6, 4), CellPosition::new(7, 4), CellPosition::new(8, 4),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(10),
           region: Region(vec![
             CellPosition::new(8, 5), CellPosition::new(8, 6),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(16),
           region: Region(vec![
             CellPosition::new(6, 7), CellPosition::new(6, 6), CellPosition::new(7, 6),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(17),
@@ -164,6 +188,7 @@ fn check_killer_9x9_1_solve() {
             CellPosition::new(7, 7), CellPosition::new(7, 8),
             CellPosition::new(8, 7), CellPosition::new(8, 8),
           ]),
+          ..Default::default()
         },
       ]
     );
@@ -196,66 +221,82 @@ fn check_killer_9x9_2_solve() {
         KillerCage {
           sum: Some(6),
           region: Region(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(18),
           region: Region(vec![ CellPosition::new(0, 2), CellPosition::new(0, 3), CellPosition::new(0, 4) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(14),
           region: Region(vec![ CellPosition::new(0, 5), CellPosition::new(0, 6), CellPosition::new(0, 7) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(16),
           region: Region(vec![ CellPosition::new(0, 8), CellPosition::new(1, 8) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(17),
           region: Region(vec![ CellPosition::new(1, 0), CellPosition::new(2, 0) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(6),
           region: Region(vec![ CellPosition::new(1, 1), CellPosition::new(2, 1) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(17),
           region: Region(vec![ CellPosition::new(1, 2), CellPosition::new(2, 2), CellPosition::new(3, 2) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(16),
           region: Region(vec![ CellPosition::new(1, 3), CellPosition::new(2, 3), CellPosition::new(3, 3) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(4),
           region: Region(vec![ CellPosition::new(1, 4), CellPosition::new(2, 4) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(12),
           region: Region(vec![ CellPosition::new(1, 5), CellPosition::new(1, 6), CellPosition::new(1, 7) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(16),
           region: Region(vec![ CellPosition::new(2, 5), CellPosition::new(2, 6), CellPosition::new(2, 7) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(9),
           region: Region(vec![ CellPosition::new(2, 8), CellPosition::new(3, 8) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(4),
           region: Region(vec![ CellPosition::new(3, 0), CellPosition::new(3, 1) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(23),
           region: Region(vec![ CellPosition::new(3, 5), CellPosition::new(3, 6), CellPosition::new(3, 7) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(15),
           region: Region(vec![ CellPosition::new(4, 0), CellPosition::new(4, 1), CellPosition::new(4, 2) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(16),
           region: Region(vec![ CellPosition::new(4, 6), CellPosition::new(4, 7), CellPosition::new(4, 8) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(21),
@@ -263,62 +304,77 @@ fn check_killer_9x9_2_solve() {
             CellPosition::new(3, 4), CellPosition::new(4, 3), CellPosition::new(4, 4),
             CellPosition::new(4, 5), CellPosition::new(5, 4),
           ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(9),
           region: Region(vec![ CellPosition::new(5, 0), CellPosition::new(6, 0) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(24),
           region: Region(vec![ CellPosition::new(5, 1), CellPosition::new(5, 2), CellPosition::new(5, 3) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(9),
           region: Region(vec![ CellPosition::new(5, 5), CellPosition::new(6, 5), CellPosition::new(7, 5) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(14),
           region: Region(vec![ CellPosition::new(5, 6), CellPosition::new(6, 6), CellPosition::new(7, 6) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(6),
           region: Region(vec![ CellPosition::new(5, 7), CellPosition::new(5, 8) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(7),
           region: Region(vec![ CellPosition::new(6, 1), CellPosition::new(6, 2), CellPosition::new(6, 3) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(17),
           region: Region(vec![ CellPosition::new(6, 4), CellPosition::new(7, 4) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(12),
           region: Region(vec![ CellPosition::new(6, 7), CellPosition::new(7, 7) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(14),
           region: Region(vec![ CellPosition::new(6, 8), CellPosition::new(7, 8) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(12),
           region: Region(vec![ CellPosition::new(7, 0), CellPosition::new(8, 0) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(11),
           region: Region(vec![ CellPosition::new(7, 1), CellPosition::new(7, 2), CellPosition::new(7, 3) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(23),
           region: Region(vec![ CellPosition::new(8, 1), CellPosition::new(8, 2), CellPosition::new(8, 3) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(13),
           region: Region(vec![ CellPosition::new(8, 4), CellPosition::new(8, 5), CellPosition::new(8, 6) ]),
+          ..Default::default()
         },
         KillerCage {
           sum: Some(4),
           region: Region(vec![ CellPosition::new(8, 7), CellPosition::new(8, 8) ]),
+          ..Default::default()
         },
       ]
     );
@@ -0,0 +1,143 @@
+use crate::{solver::Solver, types::{CellPosition, FixedNumber, Grid, KillerCage, Region, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_killer_non_unique_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 0, 8),
+        FixedNumber::new(0, 3, 7),
+        FixedNumber::new(0, 8, 4),
+        FixedNumber::new(1, 4, 6),
+        FixedNumber::new(1, 7, 2),
+        FixedNumber::new(1, 8, 8),
+        FixedNumber::new(4, 1, 4),
+        FixedNumber::new(4, 7, 8),
+        FixedNumber::new(5, 0, 5),
+        FixedNumber::new(5, 7, 3),
+        FixedNumber::new(6, 7, 9),
+        FixedNumber::new(8, 0, 2),
+      ]
+    )
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: Some(21),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(2, 1), CellPosition::new(1, 1), CellPosition::new(1, 0), CellPosition::new(0, 0),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(21),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(6, 0), CellPosition::new(7, 0), CellPosition::new(7, 1), CellPosition::new(8, 0),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(22),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(4, 0), CellPosition::new(4, 1), CellPosition::new(4, 2), CellPosition::new(5, 2),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(22),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(8, 6), CellPosition::new(8, 5), CellPosition::new(7, 5), CellPosition::new(7, 4), CellPosition::new(7, 6),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(23),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(3, 6), CellPosition::new(3, 5), CellPosition::new(4, 5), CellPosition::new(3, 4),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(32),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(5, 7), CellPosition::new(4, 7), CellPosition::new(6, 7), CellPosition::new(6, 6), CellPosition::new(6, 5),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(17),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(0, 3), CellPosition::new(0, 2), CellPosition::new(0, 4), CellPosition::new(1, 4), CellPosition::new(1, 3),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(20),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(4, 3), CellPosition::new(3, 3), CellPosition::new(5, 3), CellPosition::new(3, 2),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(19),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(6, 3), CellPosition::new(7, 3), CellPosition::new(6, 2), CellPosition::new(6, 4),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(19),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(0, 6), CellPosition::new(0, 5), CellPosition::new(1, 6),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(25),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(8, 3), CellPosition::new(8, 4), CellPosition::new(8, 2), CellPosition::new(7, 2),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: Some(17),
+          unique: false,
+          region: Region(vec![
+            CellPosition::new(2, 3), CellPosition::new(2, 4), CellPosition::new(2, 2),
+          ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::KillerCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
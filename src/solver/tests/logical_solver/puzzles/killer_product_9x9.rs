@@ -0,0 +1,183 @@
+use crate::{solver::Solver, types::{CellPosition, FixedNumber, Grid, KillerCage, Region, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_killer_product_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(1, 6, 5),
+        FixedNumber::new(2, 7, 7),
+        FixedNumber::new(4, 4, 1),
+        FixedNumber::new(5, 1, 8),
+      ]
+    )
+    .with_killer_cages(
+      vec![
+        KillerCage {
+          sum: None,
+          product: Some(32),
+          region: Region(vec![
+            CellPosition::new(3, 4), CellPosition::new(2, 4),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(14),
+          region: Region(vec![
+            CellPosition::new(2, 1), CellPosition::new(1, 1),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(27),
+          region: Region(vec![
+            CellPosition::new(8, 4), CellPosition::new(7, 4),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(21),
+          region: Region(vec![
+            CellPosition::new(8, 5), CellPosition::new(8, 6), CellPosition::new(8, 7),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(30),
+          region: Region(vec![
+            CellPosition::new(0, 4), CellPosition::new(0, 5), CellPosition::new(1, 5),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(320),
+          region: Region(vec![
+            CellPosition::new(5, 1), CellPosition::new(4, 1), CellPosition::new(5, 2), CellPosition::new(5, 0),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(40),
+          region: Region(vec![
+            CellPosition::new(4, 8), CellPosition::new(4, 7),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(48),
+          region: Region(vec![
+            CellPosition::new(4, 6), CellPosition::new(5, 6), CellPosition::new(6, 6),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(24),
+          region: Region(vec![
+            CellPosition::new(0, 1), CellPosition::new(0, 0),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(12),
+          region: Region(vec![
+            CellPosition::new(8, 8), CellPosition::new(7, 8),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(54),
+          region: Region(vec![
+            CellPosition::new(3, 1), CellPosition::new(3, 0), CellPosition::new(4, 0),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(7),
+          region: Region(vec![
+            CellPosition::new(5, 8), CellPosition::new(6, 8),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(42),
+          region: Region(vec![
+            CellPosition::new(0, 3), CellPosition::new(1, 3), CellPosition::new(1, 4),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(6),
+          region: Region(vec![
+            CellPosition::new(4, 5), CellPosition::new(4, 4),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(36),
+          region: Region(vec![
+            CellPosition::new(6, 1), CellPosition::new(7, 1), CellPosition::new(7, 2),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(18),
+          region: Region(vec![
+            CellPosition::new(2, 5), CellPosition::new(3, 5), CellPosition::new(2, 6),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(224),
+          region: Region(vec![
+            CellPosition::new(5, 5), CellPosition::new(5, 4), CellPosition::new(6, 5),
+          ]),
+          ..Default::default()
+        },
+        KillerCage {
+          sum: None,
+          product: Some(45),
+          region: Region(vec![
+            CellPosition::new(0, 2), CellPosition::new(1, 2), CellPosition::new(2, 2),
+          ]),
+          ..Default::default()
+        },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::Killer45Product));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
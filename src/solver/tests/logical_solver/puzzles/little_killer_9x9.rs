@@ -0,0 +1,63 @@
+use crate::{solver::Solver, types::{CellDirection, CellPosition, FixedNumber, Grid, LittleKiller, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_little_killer_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 3, 5),
+        FixedNumber::new(0, 8, 4),
+        FixedNumber::new(1, 0, 4),
+        FixedNumber::new(1, 4, 3),
+        FixedNumber::new(1, 7, 1),
+        FixedNumber::new(3, 1, 4),
+        FixedNumber::new(3, 7, 2),
+        FixedNumber::new(4, 6, 3),
+        FixedNumber::new(5, 2, 5),
+        FixedNumber::new(5, 7, 8),
+        FixedNumber::new(6, 2, 3),
+        FixedNumber::new(6, 3, 9),
+        FixedNumber::new(6, 7, 5),
+        FixedNumber::new(7, 1, 8),
+        FixedNumber::new(7, 3, 2),
+        FixedNumber::new(7, 4, 4),
+        FixedNumber::new(7, 8, 6),
+        FixedNumber::new(8, 0, 6),
+        FixedNumber::new(8, 1, 2),
+        FixedNumber::new(8, 8, 3),
+      ]
+    )
+    .with_little_killers(
+      vec![
+        LittleKiller { sum: 36, cell: CellPosition::new(2, 0), direction: CellDirection { row: 1, col: 1 } },
+        LittleKiller { sum: 29, cell: CellPosition::new(8, 2), direction: CellDirection { row: -1, col: 1 } },
+        LittleKiller { sum: 26, cell: CellPosition::new(8, 3), direction: CellDirection { row: -1, col: -1 } },
+        LittleKiller { sum: 41, cell: CellPosition::new(7, 8), direction: CellDirection { row: -1, col: -1 } },
+        LittleKiller { sum: 21, cell: CellPosition::new(0, 5), direction: CellDirection { row: 1, col: 1 } },
+        LittleKiller { sum: 23, cell: CellPosition::new(0, 3), direction: CellDirection { row: 1, col: -1 } },
+        LittleKiller { sum: 51, cell: CellPosition::new(1, 0), direction: CellDirection { row: 1, col: 1 } },
+        LittleKiller { sum: 22, cell: CellPosition::new(4, 0), direction: CellDirection { row: -1, col: 1 } },
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 6, 7, 5, 9, 1, 2, 3, 4 ],
+      vec![ 4, 5, 2, 6, 3, 8, 9, 1, 7 ],
+      vec![ 3, 9, 1, 7, 2, 4, 5, 6, 8 ],
+      vec![ 7, 4, 8, 3, 1, 9, 6, 2, 5 ],
+      vec![ 2, 1, 6, 8, 5, 7, 3, 4, 9 ],
+      vec![ 9, 3, 5, 4, 6, 2, 7, 8, 1 ],
+      vec![ 1, 7, 3, 9, 8, 6, 4, 5, 2 ],
+      vec![ 5, 8, 9, 2, 4, 3, 1, 7, 6 ],
+      vec![ 6, 2, 4, 1, 7, 5, 8, 9, 3 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::LittleKillerCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
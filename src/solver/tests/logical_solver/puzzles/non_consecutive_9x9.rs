@@ -0,0 +1,44 @@
+use crate::{solver::Solver, types::{FixedNumber, Grid, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_non_consecutive_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 5, 5),
+        FixedNumber::new(1, 1, 7),
+        FixedNumber::new(2, 0, 6),
+        FixedNumber::new(2, 2, 5),
+        FixedNumber::new(2, 5, 9),
+        FixedNumber::new(3, 1, 6),
+        FixedNumber::new(3, 7, 4),
+        FixedNumber::new(4, 3, 3),
+        FixedNumber::new(5, 4, 7),
+        FixedNumber::new(5, 8, 1),
+        FixedNumber::new(6, 0, 3),
+        FixedNumber::new(7, 5, 1),
+      ]
+    )
+    .with_non_consecutive();
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::NonConsecutiveCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
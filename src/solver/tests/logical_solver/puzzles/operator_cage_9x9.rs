@@ -0,0 +1,127 @@
+use crate::{solver::Solver, types::{CageOperator, CellPosition, FixedNumber, Grid, OperatorCage, Region, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_operator_cage_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(1, 0, 4),
+        FixedNumber::new(3, 1, 6),
+      ]
+    )
+    .with_operator_cages(
+      vec![
+        OperatorCage::new(
+          CageOperator::Add,
+          15,
+          Region(vec![ CellPosition::new(0, 1), CellPosition::new(0, 0), CellPosition::new(1, 0) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          5,
+          Region(vec![ CellPosition::new(3, 0), CellPosition::new(2, 0) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Multiply,
+          189,
+          Region(vec![ CellPosition::new(6, 8), CellPosition::new(5, 8), CellPosition::new(5, 7), CellPosition::new(6, 7) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Divide,
+          7,
+          Region(vec![ CellPosition::new(7, 5), CellPosition::new(8, 5) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          4,
+          Region(vec![ CellPosition::new(8, 2), CellPosition::new(8, 3) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          5,
+          Region(vec![ CellPosition::new(2, 5), CellPosition::new(2, 4) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          4,
+          Region(vec![ CellPosition::new(8, 8), CellPosition::new(7, 8) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Multiply,
+          28,
+          Region(vec![ CellPosition::new(3, 7), CellPosition::new(2, 7), CellPosition::new(2, 6) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Divide,
+          5,
+          Region(vec![ CellPosition::new(8, 7), CellPosition::new(7, 7) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Multiply,
+          180,
+          Region(vec![ CellPosition::new(7, 4), CellPosition::new(7, 3), CellPosition::new(6, 4), CellPosition::new(6, 3) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Add,
+          8,
+          Region(vec![ CellPosition::new(1, 5), CellPosition::new(1, 6) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          5,
+          Region(vec![ CellPosition::new(0, 4), CellPosition::new(0, 3) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Add,
+          14,
+          Region(vec![ CellPosition::new(3, 3), CellPosition::new(3, 4), CellPosition::new(4, 4) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Add,
+          13,
+          Region(vec![ CellPosition::new(2, 3), CellPosition::new(2, 2) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Multiply,
+          45,
+          Region(vec![ CellPosition::new(0, 5), CellPosition::new(0, 6) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Multiply,
+          54,
+          Region(vec![ CellPosition::new(1, 4), CellPosition::new(1, 3), CellPosition::new(1, 2) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          4,
+          Region(vec![ CellPosition::new(4, 6), CellPosition::new(5, 6) ]),
+        ),
+        OperatorCage::new(
+          CageOperator::Subtract,
+          4,
+          Region(vec![ CellPosition::new(6, 0), CellPosition::new(7, 0) ]),
+        ),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 3, 1, 7, 2, 5, 9, 6, 4 ],
+      vec![ 4, 7, 9, 1, 6, 3, 5, 2, 8 ],
+      vec![ 6, 2, 5, 8, 4, 9, 1, 7, 3 ],
+      vec![ 1, 6, 3, 5, 8, 2, 7, 4, 9 ],
+      vec![ 9, 4, 7, 3, 1, 6, 2, 8, 5 ],
+      vec![ 5, 8, 2, 9, 7, 4, 6, 3, 1 ],
+      vec![ 3, 1, 6, 2, 5, 8, 4, 9, 7 ],
+      vec![ 7, 9, 4, 6, 3, 1, 8, 5, 2 ],
+      vec![ 2, 5, 8, 4, 9, 7, 3, 1, 6 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::OperatorCageCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,103 @@
+use crate::{solver::Solver, types::{CellPosition, FixedNumber, Grid, ParityLine, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_parity_line_9x9_1_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 1, 6),
+        FixedNumber::new(0, 5, 1),
+        FixedNumber::new(0, 8, 4),
+        FixedNumber::new(1, 4, 3),
+        FixedNumber::new(1, 8, 7),
+        FixedNumber::new(2, 1, 9),
+        FixedNumber::new(2, 2, 1),
+        FixedNumber::new(3, 7, 2),
+        FixedNumber::new(4, 2, 6),
+        FixedNumber::new(4, 8, 9),
+        FixedNumber::new(5, 7, 8),
+        FixedNumber::new(6, 7, 5),
+        FixedNumber::new(7, 2, 9),
+        FixedNumber::new(7, 4, 4),
+        FixedNumber::new(8, 5, 5),
+      ]
+    )
+    .with_parity_lines(
+      vec![
+        ParityLine(vec![
+          CellPosition::new(2, 1),
+          CellPosition::new(1, 0),
+          CellPosition::new(2, 0),
+          CellPosition::new(3, 1),
+          CellPosition::new(3, 0),
+        ]),
+        ParityLine(vec![
+          CellPosition::new(6, 3),
+          CellPosition::new(7, 3),
+          CellPosition::new(6, 2),
+        ]),
+        ParityLine(vec![
+          CellPosition::new(6, 6),
+          CellPosition::new(5, 6),
+          CellPosition::new(6, 5),
+          CellPosition::new(7, 6),
+          CellPosition::new(8, 6),
+          CellPosition::new(7, 5),
+          CellPosition::new(7, 4),
+        ]),
+        ParityLine(vec![
+          CellPosition::new(0, 0),
+          CellPosition::new(1, 1),
+          CellPosition::new(1, 2),
+        ]),
+        ParityLine(vec![
+          CellPosition::new(3, 6),
+          CellPosition::new(2, 6),
+          CellPosition::new(2, 5),
+          CellPosition::new(3, 5),
+          CellPosition::new(2, 4),
+          CellPosition::new(2, 3),
+          CellPosition::new(3, 2),
+          CellPosition::new(2, 2),
+        ]),
+        ParityLine(vec![
+          CellPosition::new(3, 7),
+          CellPosition::new(3, 8),
+          CellPosition::new(2, 8),
+          CellPosition::new(1, 7),
+          CellPosition::new(0, 6),
+        ]),
+        ParityLine(vec![
+          CellPosition::new(4, 1),
+          CellPosition::new(4, 2),
+          CellPosition::new(5, 2),
+          CellPosition::new(5, 3),
+          CellPosition::new(4, 4),
+          CellPosition::new(5, 4),
+          CellPosition::new(4, 5),
+          CellPosition::new(5, 5),
+        ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 6, 7, 5, 9, 1, 2, 3, 4 ],
+      vec![ 4, 5, 2, 6, 3, 8, 9, 1, 7 ],
+      vec![ 3, 9, 1, 7, 2, 4, 5, 6, 8 ],
+      vec![ 7, 4, 8, 3, 1, 9, 6, 2, 5 ],
+      vec![ 2, 1, 6, 8, 5, 7, 3, 4, 9 ],
+      vec![ 9, 3, 5, 4, 6, 2, 7, 8, 1 ],
+      vec![ 1, 7, 3, 9, 8, 6, 4, 5, 2 ],
+      vec![ 5, 8, 9, 2, 4, 3, 1, 7, 6 ],
+      vec![ 6, 2, 4, 1, 7, 5, 8, 9, 3 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::ParityLineCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,53 @@
+use crate::{solver::Solver, types::{CellPosition, FixedNumber, Grid, Quadruple, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_quadruple_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 4, 9),
+        FixedNumber::new(1, 2, 2),
+        FixedNumber::new(1, 3, 6),
+        FixedNumber::new(5, 6, 7),
+      ]
+    )
+    .with_quadruples(
+      vec![
+        Quadruple::new(CellPosition::new(2, 1), vec![1, 8, 9]),
+        Quadruple::new(CellPosition::new(4, 4), vec![2, 5, 6, 7]),
+        Quadruple::new(CellPosition::new(6, 6), vec![1, 4, 5, 7]),
+        Quadruple::new(CellPosition::new(6, 3), vec![4, 9]),
+        Quadruple::new(CellPosition::new(6, 0), vec![5, 8]),
+        Quadruple::new(CellPosition::new(0, 4), vec![3, 8, 9]),
+        Quadruple::new(CellPosition::new(2, 0), vec![3, 4, 9]),
+        Quadruple::new(CellPosition::new(0, 7), vec![1, 3, 4, 7]),
+        Quadruple::new(CellPosition::new(3, 7), vec![2, 4, 5, 9]),
+        Quadruple::new(CellPosition::new(7, 3), vec![1, 2, 4, 7]),
+        Quadruple::new(CellPosition::new(3, 4), vec![5, 7, 9]),
+        Quadruple::new(CellPosition::new(3, 6), vec![2, 3, 4, 6]),
+        Quadruple::new(CellPosition::new(5, 1), vec![3, 3, 5, 7]),
+        Quadruple::new(CellPosition::new(3, 0), vec![1, 2, 4, 7]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 6, 7, 5, 9, 1, 2, 3, 4 ],
+      vec![ 4, 5, 2, 6, 3, 8, 9, 1, 7 ],
+      vec![ 3, 9, 1, 7, 2, 4, 5, 6, 8 ],
+      vec![ 7, 4, 8, 3, 1, 9, 6, 2, 5 ],
+      vec![ 2, 1, 6, 8, 5, 7, 3, 4, 9 ],
+      vec![ 9, 3, 5, 4, 6, 2, 7, 8, 1 ],
+      vec![ 1, 7, 3, 9, 8, 6, 4, 5, 2 ],
+      vec![ 5, 8, 9, 2, 4, 3, 1, 7, 6 ],
+      vec![ 6, 2, 4, 1, 7, 5, 8, 9, 3 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::QuadrupleCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
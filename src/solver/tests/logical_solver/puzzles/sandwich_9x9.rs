@@ -0,0 +1,57 @@
+use crate::{solver::Solver, types::{FixedNumber, Grid, OutsideClue, OutsideCluePosition, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_sandwich_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 1, 6),
+        FixedNumber::new(0, 4, 9),
+        FixedNumber::new(0, 8, 4),
+        FixedNumber::new(1, 5, 8),
+        FixedNumber::new(1, 7, 1),
+        FixedNumber::new(1, 8, 7),
+        FixedNumber::new(3, 7, 2),
+        FixedNumber::new(4, 2, 6),
+        FixedNumber::new(4, 3, 8),
+        FixedNumber::new(6, 2, 3),
+        FixedNumber::new(7, 4, 4),
+        FixedNumber::new(8, 5, 5),
+      ]
+    )
+    .with_sandwiches(
+      vec![
+        OutsideClue::new(5, OutsideCluePosition::Top, 4),
+        OutsideClue::new(22, OutsideCluePosition::Bottom, 2),
+        OutsideClue::new(0, OutsideCluePosition::Top, 8),
+        OutsideClue::new(4, OutsideCluePosition::Bottom, 1),
+        OutsideClue::new(32, OutsideCluePosition::Bottom, 7),
+        OutsideClue::new(0, OutsideCluePosition::Left, 3),
+        OutsideClue::new(33, OutsideCluePosition::Right, 4),
+        OutsideClue::new(0, OutsideCluePosition::Right, 2),
+        OutsideClue::new(25, OutsideCluePosition::Top, 6),
+        OutsideClue::new(2, OutsideCluePosition::Top, 3),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 6, 7, 5, 9, 1, 2, 3, 4 ],
+      vec![ 4, 5, 2, 6, 3, 8, 9, 1, 7 ],
+      vec![ 3, 9, 1, 7, 2, 4, 5, 6, 8 ],
+      vec![ 7, 4, 8, 3, 1, 9, 6, 2, 5 ],
+      vec![ 2, 1, 6, 8, 5, 7, 3, 4, 9 ],
+      vec![ 9, 3, 5, 4, 6, 2, 7, 8, 1 ],
+      vec![ 1, 7, 3, 9, 8, 6, 4, 5, 2 ],
+      vec![ 5, 8, 9, 2, 4, 3, 1, 7, 6 ],
+      vec![ 6, 2, 4, 1, 7, 5, 8, 9, 3 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::SandwichCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,65 @@
+use crate::{solver::Solver, types::{FixedNumber, Grid, OutsideClue, OutsideCluePosition, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_skyscraper_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(1, 1, 5),
+        FixedNumber::new(1, 7, 1),
+        FixedNumber::new(2, 2, 1),
+        FixedNumber::new(3, 3, 3),
+        FixedNumber::new(3, 6, 6),
+        FixedNumber::new(3, 7, 2),
+        FixedNumber::new(4, 3, 8),
+        FixedNumber::new(5, 4, 6),
+        FixedNumber::new(6, 1, 7),
+        FixedNumber::new(6, 5, 6),
+        FixedNumber::new(6, 8, 2),
+        FixedNumber::new(7, 3, 2),
+        FixedNumber::new(7, 4, 4),
+        FixedNumber::new(8, 8, 3),
+      ]
+    )
+    .with_skyscrapers(
+      vec![
+        OutsideClue::new(4, OutsideCluePosition::Top, 8),
+        OutsideClue::new(1, OutsideCluePosition::Top, 4),
+        OutsideClue::new(1, OutsideCluePosition::Bottom, 7),
+        OutsideClue::new(4, OutsideCluePosition::Top, 7),
+        OutsideClue::new(1, OutsideCluePosition::Right, 4),
+        OutsideClue::new(3, OutsideCluePosition::Left, 6),
+        OutsideClue::new(4, OutsideCluePosition::Bottom, 5),
+        OutsideClue::new(2, OutsideCluePosition::Bottom, 6),
+        OutsideClue::new(2, OutsideCluePosition::Left, 2),
+        OutsideClue::new(3, OutsideCluePosition::Bottom, 3),
+        OutsideClue::new(3, OutsideCluePosition::Left, 7),
+        OutsideClue::new(2, OutsideCluePosition::Top, 6),
+        OutsideClue::new(5, OutsideCluePosition::Top, 3),
+        OutsideClue::new(2, OutsideCluePosition::Right, 1),
+        OutsideClue::new(2, OutsideCluePosition::Top, 0),
+        OutsideClue::new(4, OutsideCluePosition::Left, 8),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 6, 7, 5, 9, 1, 2, 3, 4 ],
+      vec![ 4, 5, 2, 6, 3, 8, 9, 1, 7 ],
+      vec![ 3, 9, 1, 7, 2, 4, 5, 6, 8 ],
+      vec![ 7, 4, 8, 3, 1, 9, 6, 2, 5 ],
+      vec![ 2, 1, 6, 8, 5, 7, 3, 4, 9 ],
+      vec![ 9, 3, 5, 4, 6, 2, 7, 8, 1 ],
+      vec![ 1, 7, 3, 9, 8, 6, 4, 5, 2 ],
+      vec![ 5, 8, 9, 2, 4, 3, 1, 7, 6 ],
+      vec![ 6, 2, 4, 1, 7, 5, 8, 9, 3 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::SkyscraperCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
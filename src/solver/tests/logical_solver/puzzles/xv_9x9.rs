@@ -0,0 +1,59 @@
+use crate::{solver::Solver, types::{CellPosition, FixedNumber, Grid, KropkiDot, Rule, SolutionType, SudokuConstraints}};
+
+#[test]
+fn check_xv_negative_9x9_solve() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(1, 0, 4),
+        FixedNumber::new(3, 7, 2),
+        FixedNumber::new(8, 1, 2),
+      ]
+    )
+    .with_kropki_dots(
+      vec![
+        KropkiDot::x(CellPosition::new(0, 4), CellPosition::new(0, 5)),
+        KropkiDot::v(CellPosition::new(0, 6), CellPosition::new(0, 7)),
+        KropkiDot::v(CellPosition::new(1, 4), CellPosition::new(2, 4)),
+        KropkiDot::x(CellPosition::new(1, 6), CellPosition::new(1, 7)),
+        KropkiDot::x(CellPosition::new(2, 0), CellPosition::new(3, 0)),
+        KropkiDot::x(CellPosition::new(2, 1), CellPosition::new(2, 2)),
+        KropkiDot::x(CellPosition::new(2, 3), CellPosition::new(3, 3)),
+        KropkiDot::v(CellPosition::new(3, 1), CellPosition::new(4, 1)),
+        KropkiDot::x(CellPosition::new(3, 4), CellPosition::new(3, 5)),
+        KropkiDot::x(CellPosition::new(4, 5), CellPosition::new(4, 6)),
+        KropkiDot::x(CellPosition::new(4, 6), CellPosition::new(5, 6)),
+        KropkiDot::x(CellPosition::new(4, 8), CellPosition::new(5, 8)),
+        KropkiDot::x(CellPosition::new(5, 0), CellPosition::new(6, 0)),
+        KropkiDot::x(CellPosition::new(5, 1), CellPosition::new(6, 1)),
+        KropkiDot::x(CellPosition::new(5, 3), CellPosition::new(5, 4)),
+        KropkiDot::x(CellPosition::new(6, 1), CellPosition::new(6, 2)),
+        KropkiDot::x(CellPosition::new(6, 5), CellPosition::new(6, 6)),
+        KropkiDot::v(CellPosition::new(6, 6), CellPosition::new(7, 6)),
+        KropkiDot::x(CellPosition::new(7, 1), CellPosition::new(8, 1)),
+        KropkiDot::v(CellPosition::new(8, 2), CellPosition::new(8, 3)),
+      ]
+    )
+    .with_xv_negative();
+  let mut solver = Solver::new(constraints);
+  let result = solver.logical_solve();
+  assert_eq!(result.solution_type, SolutionType::Full);
+  assert_eq!(
+    result.solution.unwrap(),
+    Grid(vec![
+      vec![ 8, 6, 7, 5, 9, 1, 2, 3, 4 ],
+      vec![ 4, 5, 2, 6, 3, 8, 9, 1, 7 ],
+      vec![ 3, 9, 1, 7, 2, 4, 5, 6, 8 ],
+      vec![ 7, 4, 8, 3, 1, 9, 6, 2, 5 ],
+      vec![ 2, 1, 6, 8, 5, 7, 3, 4, 9 ],
+      vec![ 9, 3, 5, 4, 6, 2, 7, 8, 1 ],
+      vec![ 1, 7, 3, 9, 8, 6, 4, 5, 2 ],
+      vec![ 5, 8, 9, 2, 4, 3, 1, 7, 6 ],
+      vec![ 6, 2, 4, 1, 7, 5, 8, 9, 3 ],
+    ])
+  );
+  let rules: Vec<_> = result.steps.iter().map(|step| step.rule).collect();
+  assert!(rules.contains(&Rule::KropkiChainCandidates));
+  assert!(!rules.contains(&Rule::NishioForcingChains));
+  insta::assert_yaml_snapshot!(result.steps);
+}
@@ -0,0 +1,45 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, quadruple_candidates::QuadrupleCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, Quadruple, Rule, SudokuConstraints}};
+
+#[test]
+fn check_quadruple_candidates_all_slots_filled() {
+  let constraints = SudokuConstraints::new(9)
+    .with_quadruples(
+      vec![ Quadruple::new(CellPosition::new(0, 0), vec![1, 2, 3, 4]) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = QuadrupleCandidates.run(&solver);
+  assert_eq!(steps.len(), 4);
+
+  let cells = [ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(1, 0), CellPosition::new(1, 1) ];
+  for (step, cell) in steps.iter().zip(cells) {
+    assert_eq!(step.rule, Rule::QuadrupleCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, vec![5, 6, 7, 8, 9]);
+    assert_eq!(step.areas, vec![ Area::Quadruple(0) ]);
+  }
+}
+
+#[test]
+fn check_quadruple_candidates_forced_value() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 5, 5), FixedNumber::new(5, 0, 5) ]
+    )
+    .with_quadruples(
+      vec![ Quadruple::new(CellPosition::new(0, 0), vec![5]) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = QuadrupleCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+
+  // The 5 can only go in one of the cells
+  let step = &steps[0];
+  assert_eq!(step.rule, Rule::QuadrupleCandidates);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(1, 1) ]);
+  assert_eq!(step.values, vec![1, 2, 3, 4, 6, 7, 8, 9]);
+  assert_eq!(step.areas, vec![ Area::Quadruple(0) ]);
+}
@@ -0,0 +1,52 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, region_sum_line_candidates::RegionSumLineCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, RegionSumLine, Rule, SudokuConstraints}};
+
+#[test]
+fn check_region_sum_line_candidates_known_sum() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 2, 3)]
+    )
+    .with_region_sum_lines(
+      vec![
+        RegionSumLine(vec![ CellPosition::new(0, 2), CellPosition::new(1, 3), CellPosition::new(1, 4) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = RegionSumLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  for (index, step) in steps.iter().enumerate() {
+    assert_eq!(step.rule, Rule::RegionSumLineCandidates);
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(1, 3 + index) ]);
+    assert_eq!(step.values, vec![3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(step.areas, vec![ Area::RegionSumLine(0) ]);
+  }
+}
+
+#[test]
+fn check_region_sum_line_candidates_unknown_sum() {
+  let constraints = SudokuConstraints::new(9)
+    .with_region_sum_lines(
+      vec![
+        RegionSumLine(vec![ CellPosition::new(2, 1), CellPosition::new(2, 2), CellPosition::new(2, 3) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = RegionSumLineCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  // The single cell segment limits the sum to 9, but two different digits add up to at least 3
+  let expected = [
+    (CellPosition::new(2, 1), vec![9]),
+    (CellPosition::new(2, 2), vec![9]),
+    (CellPosition::new(2, 3), vec![1, 2]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+  }
+}
@@ -0,0 +1,51 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, sandwich_candidates::SandwichCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, OutsideClue, OutsideCluePosition, Rule, SudokuConstraints}};
+
+#[test]
+fn check_sandwich_candidates_max_sum() {
+  let constraints = SudokuConstraints::new(9)
+    .with_sandwiches(
+      vec![ OutsideClue::new(35, OutsideCluePosition::Top, 4) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SandwichCandidates.run(&solver);
+  assert_eq!(steps.len(), 9);
+
+  // All digits except the crusts must be between them, so the crusts are at the ends
+  for (row, step) in steps.iter().enumerate() {
+    let expected_values = if row == 0 || row == 8 {
+      vec![2, 3, 4, 5, 6, 7, 8]
+    } else {
+      vec![1, 9]
+    };
+    assert_eq!(step.rule, Rule::SandwichCandidates);
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(row, 4) ]);
+    assert_eq!(step.values, expected_values);
+    assert_eq!(step.areas, vec![ Area::Sandwich(0) ]);
+  }
+}
+
+#[test]
+fn check_sandwich_candidates_zero_sum() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 3, 1) ]
+    )
+    .with_sandwiches(
+      vec![ OutsideClue::new(0, OutsideCluePosition::Right, 0) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SandwichCandidates.run(&solver);
+  assert_eq!(steps.len(), 6);
+
+  // The 9 must be next to the 1
+  for (step, col) in steps.iter().zip([ 8, 7, 6, 5, 1, 0 ]) {
+    assert_eq!(step.rule, Rule::SandwichCandidates);
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(0, col) ]);
+    assert_eq!(step.values, vec![9]);
+    assert_eq!(step.areas, vec![ Area::Sandwich(0) ]);
+  }
+}
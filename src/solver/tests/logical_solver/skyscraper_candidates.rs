@@ -0,0 +1,135 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, skyscraper_candidates::SkyscraperCandidates, technique::Technique}}, types::{Area, CellPosition, FixedNumber, OutsideClue, OutsideCluePosition, Rule, SudokuConstraints}};
+
+#[test]
+fn check_skyscraper_candidates_one_visible() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 7, 2), FixedNumber::new(0, 8, 1) ]
+    )
+    .with_skyscrapers(
+      vec![ OutsideClue::new(1, OutsideCluePosition::Left, 0) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SkyscraperCandidates.run(&solver);
+  assert_eq!(steps.len(), 7);
+
+  // The tallest building must be first to hide all the others
+  for (col, step) in steps.iter().enumerate() {
+    let expected_values = if col == 0 {
+      vec![3, 4, 5, 6, 7, 8]
+    } else {
+      vec![9]
+    };
+    assert_eq!(step.rule, Rule::SkyscraperCandidates);
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(0, col) ]);
+    assert_eq!(step.values, expected_values);
+    assert_eq!(step.areas, vec![ Area::Skyscraper(0) ]);
+  }
+}
+
+#[test]
+fn check_skyscraper_candidates_all_visible() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 2, 9), FixedNumber::new(8, 2, 1) ]
+    )
+    .with_skyscrapers(
+      vec![ OutsideClue::new(9, OutsideCluePosition::Bottom, 2) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SkyscraperCandidates.run(&solver);
+  assert_eq!(steps.len(), 7);
+
+  // The buildings must be increasing from the clue
+  for (index, step) in steps.iter().enumerate() {
+    let expected_values: Vec<u32> = (2..=8).filter(|&value| value != index as u32 + 2).collect();
+    assert_eq!(step.rule, Rule::SkyscraperCandidates);
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(7 - index, 2) ]);
+    assert_eq!(step.values, expected_values);
+    assert_eq!(step.areas, vec![ Area::Skyscraper(0) ]);
+  }
+}
+
+#[test]
+fn check_skyscraper_candidates_two_visible() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(7, 5, 1), FixedNumber::new(8, 5, 2) ]
+    )
+    .with_skyscrapers(
+      vec![ OutsideClue::new(2, OutsideCluePosition::Top, 5) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SkyscraperCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  // The 9 can't be first because another building must be visible in front of it,
+  // and an 8 in the second cell would make the 9 a third visible building
+  let expected = [
+    (CellPosition::new(0, 5), vec![9]),
+    (CellPosition::new(1, 5), vec![8]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.rule, Rule::SkyscraperCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+    assert_eq!(step.areas, vec![ Area::Skyscraper(0) ]);
+  }
+}
+
+#[test]
+fn check_skyscraper_candidates_one_visible_empty_grid() {
+  let constraints = SudokuConstraints::new(9)
+    .with_skyscrapers(
+      vec![ OutsideClue::new(1, OutsideCluePosition::Left, 0) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SkyscraperCandidates.run(&solver);
+  assert_eq!(steps.len(), 1);
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(steps[0].values, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+  assert_eq!(steps[0].areas, vec![ Area::Skyscraper(0) ]);
+}
+
+#[test]
+fn check_skyscraper_candidates_all_visible_empty_grid() {
+  let constraints = SudokuConstraints::new(9)
+    .with_skyscrapers(
+      vec![ OutsideClue::new(9, OutsideCluePosition::Top, 3) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SkyscraperCandidates.run(&solver);
+  assert_eq!(steps.len(), 9);
+  for (row, step) in steps.iter().enumerate() {
+    let expected_values: Vec<u32> = (1..=9).filter(|&value| value != row as u32 + 1).collect();
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(row, 3) ]);
+    assert_eq!(step.values, expected_values);
+  }
+}
+
+#[test]
+fn check_skyscraper_candidates_distance_bounds_empty_grid() {
+  let constraints = SudokuConstraints::new(9)
+    .with_skyscrapers(
+      vec![ OutsideClue::new(3, OutsideCluePosition::Right, 4) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = SkyscraperCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(4, 8) ]);
+  assert_eq!(steps[0].values, vec![8, 9]);
+  assert_eq!(steps[1].affected_cells, vec![ CellPosition::new(4, 7) ]);
+  assert_eq!(steps[1].values, vec![9]);
+}
@@ -1,4 +1,6 @@
-use crate::{solver::{Solver, logical_solver::{candidates::Candidates, locked_candidates::LockedCandidates, technique::Technique, thermo_candidates::ThermoCandidates}}, types::{CellPosition, FixedNumber, Rule, SudokuConstraints, Thermo}};
+use std::collections::HashSet;
+
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, locked_candidates::LockedCandidates, technique::Technique, thermo_candidates::ThermoCandidates}}, types::{Area, BranchingThermo, CellPosition, FixedNumber, Rule, SudokuConstraints, Thermo}};
 
 #[test]
 fn check_thermo_candidates_update_hidden_single() {
@@ -87,3 +89,122 @@ fn check_thermo_candidates_update_locked_candidates() {
   assert_eq!(final_candidates.len(), 3);
   assert!(!final_candidates.contains(&3));
 }
+
+#[test]
+fn check_slow_thermo_candidates_allow_equal_values() {
+  let constraints = SudokuConstraints::new(9)
+    .with_slow_thermos(
+      vec![
+        Thermo(vec![ CellPosition::new(2, 2), CellPosition::new(3, 3), CellPosition::new(4, 4) ]),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&mut Candidates.run(&solver).first().unwrap());
+  let steps = ThermoCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+  assert!(steps.iter().all(|step| step.areas == vec![ Area::SlowThermo(0) ]));
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(2, 2) ]);
+  assert_eq!(steps[0].values, vec![ 9 ]);
+  assert_eq!(steps[1].affected_cells, vec![ CellPosition::new(3, 3) ]);
+  assert_eq!(steps[1].values, vec![ 9 ]);
+  assert_eq!(steps[2].affected_cells, vec![ CellPosition::new(4, 4) ]);
+  assert_eq!(steps[2].values, vec![ 1 ]);
+}
+
+#[test]
+fn check_branching_thermo_candidates_use_longest_branch() {
+  let constraints = SudokuConstraints::new(9)
+    .with_branching_thermos(
+      vec![
+        BranchingThermo::new(
+          CellPosition::new(0, 0),
+          vec![
+            vec![ CellPosition::new(0, 1), CellPosition::new(0, 2) ],
+            vec![ CellPosition::new(1, 0), CellPosition::new(2, 0), CellPosition::new(3, 0), CellPosition::new(4, 0) ],
+          ],
+        ),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(&mut Candidates.run(&solver).first().unwrap());
+  let steps = ThermoCandidates.run(&solver);
+  assert_eq!(steps.len(), 7);
+  assert!(steps.iter().all(|step| step.areas == vec![ Area::BranchingThermo(0) ]));
+  assert_eq!(steps[0].affected_cells, vec![ CellPosition::new(0, 0) ]);
+  assert_eq!(steps[0].values, vec![ 6, 7, 8, 9 ]);
+  assert_eq!(steps[2].affected_cells, vec![ CellPosition::new(0, 2) ]);
+  assert_eq!(steps[2].values, vec![ 1, 2 ]);
+}
+
+#[test]
+fn check_branching_thermo_peers_only_on_same_path() {
+  let constraints = SudokuConstraints::new(9)
+    .with_branching_thermos(
+      vec![
+        BranchingThermo::new(
+          CellPosition::new(4, 4),
+          vec![
+            vec![ CellPosition::new(5, 5), CellPosition::new(6, 6) ],
+            vec![ CellPosition::new(3, 5), CellPosition::new(2, 6) ],
+          ]
+        ),
+      ]
+    );
+  let solver = Solver::new(constraints);
+
+  let peers = solver.get_cell_peers(&CellPosition::new(6, 6), true);
+  assert!(peers.contains(&CellPosition::new(4, 4)));
+  assert!(peers.contains(&CellPosition::new(5, 5)));
+  assert!(!peers.contains(&CellPosition::new(3, 5)));
+}
+
+#[test]
+fn check_slow_thermo_area_candidates() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(0, 0, 3),
+        FixedNumber::new(0, 2, 5),
+      ]
+    )
+    .with_slow_thermos(
+      vec![
+        Thermo(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2) ]),
+      ]
+    );
+  let solver = Solver::new(constraints);
+
+  // Equal values are allowed, so both ends stay candidates
+  let candidates = solver.compute_area_cell_candidates(&Area::SlowThermo(0), &CellPosition::new(0, 1));
+  assert_eq!(candidates, HashSet::from([ 3, 4, 5 ]));
+}
+
+#[test]
+fn check_branching_thermo_area_candidates() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![
+        FixedNumber::new(4, 4, 2),
+        FixedNumber::new(6, 6, 7),
+        FixedNumber::new(2, 6, 4),
+      ]
+    )
+    .with_branching_thermos(
+      vec![
+        BranchingThermo::new(
+          CellPosition::new(4, 4),
+          vec![
+            vec![ CellPosition::new(5, 5), CellPosition::new(6, 6) ],
+            vec![ CellPosition::new(3, 5), CellPosition::new(2, 6) ],
+          ]
+        ),
+      ]
+    );
+  let solver = Solver::new(constraints);
+
+  // Only the path through the cell restricts it, the other branch ends at 4
+  let candidates = solver.compute_area_cell_candidates(&Area::BranchingThermo(0), &CellPosition::new(5, 5));
+  assert_eq!(candidates, HashSet::from([ 3, 4, 5, 6 ]));
+}
@@ -0,0 +1,77 @@
+use ntest::timeout;
+
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, technique::Technique, whispers_candidates::WhispersCandidates}}, types::{Area, CellPosition, FixedNumber, Rule, SudokuConstraints, Whispers}};
+
+#[test]
+fn check_whispers_candidates_middle_digit() {
+  let whispers = vec![
+    Whispers(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2) ])
+  ];
+  let constraints = SudokuConstraints::new(9)
+    .with_whispers(whispers.clone());
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = WhispersCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  // The middle cell can't be 4 or 6 because both of its neighbours would have to be 9 or 1
+  let expected_values = [ vec![5], vec![4, 5, 6], vec![5] ];
+  for (index, step) in steps.into_iter().enumerate() {
+    assert_eq!(step.rule, Rule::WhispersCandidates);
+    let cell = whispers[0][index];
+    assert_eq!(step.affected_cells, vec![cell]);
+    assert_eq!(step.values, expected_values[index]);
+    assert_eq!(step.areas, vec![ Area::Whispers(0) ]);
+    solver.apply_rule(&step);
+    assert!(!solver.candidates[cell.row][cell.col].contains(&5));
+    assert_eq!(solver.candidates[cell.row][cell.col].len(), 9 - expected_values[index].len());
+  }
+}
+
+#[test]
+fn check_whispers_candidates_fixed_value() {
+  let whispers = vec![
+    Whispers(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2) ])
+  ];
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 1, 3)]
+    )
+    .with_whispers(whispers.clone());
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = WhispersCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  for (step, cell) in steps.into_iter().zip([ whispers[0][0], whispers[0][2] ]) {
+    assert_eq!(step.rule, Rule::WhispersCandidates);
+    assert_eq!(step.affected_cells, vec![cell]);
+    assert_eq!(step.values, vec![1, 2, 4, 5, 6, 7]);
+    assert_eq!(step.areas, vec![ Area::Whispers(0) ]);
+    solver.apply_rule(&step);
+    assert_eq!(solver.candidates[cell.row][cell.col].len(), 2);
+  }
+}
+
+#[test]
+#[timeout(1000)]
+fn check_whispers_candidates_long_line() {
+  // A staircase from corner to corner is far too long to check all at once
+  let cells: Vec<CellPosition> = (0..17).map(|index| CellPosition::new(index / 2, index.div_ceil(2))).collect();
+  let constraints = SudokuConstraints::new(9)
+    .with_whispers(vec![ Whispers(cells.clone()) ]);
+  let mut solver = Solver::new(constraints);
+
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+  let steps = WhispersCandidates.run(&solver);
+  assert_eq!(steps.len(), cells.len());
+
+  for (step, cell) in steps.into_iter().zip(cells) {
+    assert_eq!(step.rule, Rule::WhispersCandidates);
+    assert_eq!(step.affected_cells, vec![cell]);
+    assert!(step.values.contains(&5));
+    assert_eq!(step.areas, vec![ Area::Whispers(0) ]);
+  }
+}
@@ -0,0 +1,53 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, technique::Technique, x_sum_candidates::XSumCandidates}}, types::{Area, CellPosition, OutsideClue, OutsideCluePosition, Rule, SudokuConstraints}};
+
+#[test]
+fn check_x_sum_candidates_sum_one() {
+  let constraints = SudokuConstraints::new(9)
+    .with_x_sums(
+      vec![ OutsideClue::new(1, OutsideCluePosition::Left, 0) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = XSumCandidates.run(&solver);
+  assert_eq!(steps.len(), 9);
+
+  // Only a 1 can sum up to itself
+  for (col, step) in steps.iter().enumerate() {
+    let expected_values = if col == 0 {
+      vec![2, 3, 4, 5, 6, 7, 8, 9]
+    } else {
+      vec![1]
+    };
+    assert_eq!(step.rule, Rule::XSumCandidates);
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(0, col) ]);
+    assert_eq!(step.values, expected_values);
+    assert_eq!(step.areas, vec![ Area::XSum(0) ]);
+  }
+}
+
+#[test]
+fn check_x_sum_candidates_sum_three() {
+  let constraints = SudokuConstraints::new(9)
+    .with_x_sums(
+      vec![ OutsideClue::new(3, OutsideCluePosition::Bottom, 4) ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = XSumCandidates.run(&solver);
+  assert_eq!(steps.len(), 9);
+
+  // The first digit must be 2, followed by a 1
+  for (index, step) in steps.iter().enumerate() {
+    let expected_values = match index {
+      0 => vec![1, 3, 4, 5, 6, 7, 8, 9],
+      1 => vec![2, 3, 4, 5, 6, 7, 8, 9],
+      _ => vec![2],
+    };
+    assert_eq!(step.rule, Rule::XSumCandidates);
+    assert_eq!(step.affected_cells, vec![ CellPosition::new(8 - index, 4) ]);
+    assert_eq!(step.values, expected_values);
+    assert_eq!(step.areas, vec![ Area::XSum(0) ]);
+  }
+}
@@ -0,0 +1,69 @@
+use crate::{types::{SudokuConstraints, CellPosition, Rule, Area, FixedNumber, KropkiDot}, solver::{Solver, logical_solver::{kropki_chain_candidates::KropkiChainCandidates, technique::Technique, candidates::Candidates}}};
+
+#[test]
+fn check_xv_v_pair_unfixed() {
+  let constraints = SudokuConstraints::new(9)
+    .with_kropki_dots(
+      vec![
+        KropkiDot::v(CellPosition::new(0, 0), CellPosition::new(0, 1)),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = KropkiChainCandidates::new(false).run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  for (step, cell) in steps.iter().zip([ CellPosition::new(0, 0), CellPosition::new(0, 1) ]) {
+    assert_eq!(step.rule, Rule::KropkiChainCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, vec![5, 6, 7, 8, 9]);
+    assert_eq!(step.areas, vec![ Area::Row(0), Area::KropkiDot(0) ]);
+  }
+}
+
+#[test]
+fn check_xv_x_pair_fixed() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 0, 3) ]
+    )
+    .with_kropki_dots(
+      vec![
+        KropkiDot::x(CellPosition::new(0, 0), CellPosition::new(0, 1)),
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = KropkiChainCandidates::new(false).run(&solver);
+  assert_eq!(steps.len(), 1);
+
+  let step = &steps[0];
+  assert_eq!(step.rule, Rule::KropkiChainCandidates);
+  assert_eq!(step.affected_cells, vec![ CellPosition::new(0, 1) ]);
+  assert_eq!(step.values, vec![1, 2, 4, 5, 6, 8, 9]);
+  assert_eq!(step.areas, vec![ Area::Row(0), Area::KropkiDot(0) ]);
+}
+
+#[test]
+fn check_xv_negative_pairs() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![ FixedNumber::new(0, 0, 3) ]
+    )
+    .with_xv_negative();
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = KropkiChainCandidates::new(true).run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  // Neighbours of the 3 can't sum up to 5 or 10 with it
+  for (index, (step, cell)) in steps.iter().zip([ CellPosition::new(0, 1), CellPosition::new(1, 0) ]).enumerate() {
+    assert_eq!(step.rule, Rule::Kropki);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, vec![2, 7]);
+    assert_eq!(step.areas, vec![ Area::KropkiDot(index) ]);
+  }
+}
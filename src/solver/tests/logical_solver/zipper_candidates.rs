@@ -0,0 +1,58 @@
+use crate::{solver::{Solver, logical_solver::{candidates::Candidates, technique::Technique, zipper_candidates::ZipperCandidates}}, types::{Area, CellPosition, FixedNumber, Rule, SudokuConstraints, Zipper}};
+
+#[test]
+fn check_zipper_candidates_known_center() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 1, 4)]
+    )
+    .with_zippers(
+      vec![
+        Zipper(vec![ CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2) ])
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = ZipperCandidates.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  // The ends see each other, so they can't both be 2
+  for (step, cell) in steps.iter().zip([ CellPosition::new(0, 0), CellPosition::new(0, 2) ]) {
+    assert_eq!(step.rule, Rule::ZipperCandidates);
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, vec![2, 5, 6, 7, 8, 9]);
+    assert_eq!(step.areas, vec![ Area::Zipper(0) ]);
+  }
+}
+
+#[test]
+fn check_zipper_candidates_unknown_sum() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(1, 0, 9)]
+    )
+    .with_zippers(
+      vec![
+        Zipper(vec![
+          CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2), CellPosition::new(1, 0),
+        ])
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+  solver.apply_rule(Candidates.run(&solver).first().unwrap());
+
+  let steps = ZipperCandidates.run(&solver);
+  assert_eq!(steps.len(), 3);
+
+  // The sum is at least 10 because of the 9, but the middle pair can't use 9 so it sums to at most 15
+  let expected = [
+    (CellPosition::new(0, 0), vec![7, 8]),
+    (CellPosition::new(0, 1), vec![1]),
+    (CellPosition::new(0, 2), vec![1]),
+  ];
+  for (step, (cell, values)) in steps.iter().zip(expected) {
+    assert_eq!(step.affected_cells, vec![ cell ]);
+    assert_eq!(step.values, values);
+  }
+}
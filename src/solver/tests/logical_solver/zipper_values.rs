@@ -0,0 +1,37 @@
+use crate::{solver::{Solver, logical_solver::{technique::Technique, zipper_values::ZipperValues}}, types::{Area, CellPosition, FixedNumber, Rule, SudokuConstraints, Zipper}};
+
+#[test]
+fn check_zipper_values() {
+  let constraints = SudokuConstraints::new(9)
+    .with_fixed_numbers(
+      vec![FixedNumber::new(0, 0, 2), FixedNumber::new(0, 1, 5), FixedNumber::new(0, 4, 7)]
+    )
+    .with_zippers(
+      vec![
+        Zipper(vec![
+          CellPosition::new(0, 0), CellPosition::new(0, 1), CellPosition::new(0, 2),
+          CellPosition::new(0, 3), CellPosition::new(0, 4),
+        ])
+      ]
+    );
+  let mut solver = Solver::new(constraints);
+
+  let steps = ZipperValues.run(&solver);
+  assert_eq!(steps.len(), 2);
+
+  let step = &steps[0];
+  assert_eq!(step.rule, Rule::ZipperValues);
+  assert_eq!(step.cells, vec![CellPosition::new(0, 3)]);
+  assert_eq!(step.values, vec![4]);
+  assert_eq!(step.areas, vec![ Area::Zipper(0) ]);
+  solver.apply_rule(step);
+  assert_eq!(solver.grid[0][3], 4);
+
+  let step = &steps[1];
+  assert_eq!(step.rule, Rule::ZipperValues);
+  assert_eq!(step.cells, vec![CellPosition::new(0, 2)]);
+  assert_eq!(step.values, vec![9]);
+  assert_eq!(step.areas, vec![ Area::Zipper(0) ]);
+  solver.apply_rule(step);
+  assert_eq!(solver.grid[0][2], 9);
+}
@@ -20,29 +20,81 @@ pub struct SudokuConstraints {
   #[tsify(optional)]
   pub thermos: Option<Vec<Thermo>>,
   #[tsify(optional)]
+  pub slow_thermos: Option<Vec<Thermo>>,
+  #[tsify(optional)]
+  pub branching_thermos: Option<Vec<BranchingThermo>>,
+  #[tsify(optional)]
   pub arrows: Option<Vec<Arrow>>,
   #[tsify(optional)]
   pub primary_diagonal: Option<bool>,
   #[tsify(optional)]
   pub secondary_diagonal: Option<bool>,
   #[tsify(optional)]
+  pub disjoint_groups: Option<bool>,
+  #[tsify(optional)]
   pub anti_knight: Option<bool>,
   #[tsify(optional)]
   pub anti_king: Option<bool>,
   #[tsify(optional)]
+  pub non_consecutive: Option<bool>,
+  #[tsify(optional)]
+  pub diagonal_non_consecutive: Option<bool>,
+  #[tsify(optional)]
   pub kropki_dots: Option<Vec<KropkiDot>>,
   #[tsify(optional)]
   pub kropki_negative: Option<bool>,
   #[tsify(optional)]
+  pub xv_negative: Option<bool>,
+  #[tsify(optional)]
   pub odd_cells: Option<Vec<CellPosition>>,
   #[tsify(optional)]
   pub even_cells: Option<Vec<CellPosition>>,
   #[tsify(optional)]
+  pub minimum_cells: Option<Vec<CellPosition>>,
+  #[tsify(optional)]
+  pub maximum_cells: Option<Vec<CellPosition>>,
+  #[tsify(optional)]
   pub top_bottom: Option<bool>,
   #[tsify(optional)]
   pub renbans: Option<Vec<Renban>>,
   #[tsify(optional)]
   pub palindromes: Option<Vec<Palindrome>>,
+  #[tsify(optional)]
+  pub whispers: Option<Vec<Whispers>>,
+  #[tsify(optional)]
+  pub difference_lines: Option<Vec<DifferenceLine>>,
+  #[tsify(optional)]
+  pub between_lines: Option<Vec<BetweenLine>>,
+  #[tsify(optional)]
+  pub region_sum_lines: Option<Vec<RegionSumLine>>,
+  #[tsify(optional)]
+  pub entropic_lines: Option<Vec<EntropicLine>>,
+  #[tsify(optional)]
+  pub parity_lines: Option<Vec<ParityLine>>,
+  #[tsify(optional)]
+  pub zippers: Option<Vec<Zipper>>,
+  #[tsify(optional)]
+  pub modular_lines: Option<Vec<ModularLine>>,
+  #[tsify(optional)]
+  pub nabner_lines: Option<Vec<NabnerLine>>,
+  #[tsify(optional)]
+  pub little_killers: Option<Vec<LittleKiller>>,
+  #[tsify(optional)]
+  pub sandwiches: Option<Vec<OutsideClue>>,
+  #[tsify(optional)]
+  pub x_sums: Option<Vec<OutsideClue>>,
+  #[tsify(optional)]
+  pub skyscrapers: Option<Vec<OutsideClue>>,
+  #[tsify(optional)]
+  pub quadruples: Option<Vec<Quadruple>>,
+  #[tsify(optional)]
+  pub inequalities: Option<Vec<Inequality>>,
+  #[tsify(optional)]
+  pub clones: Option<Vec<CloneGroup>>,
+  #[tsify(optional)]
+  pub operator_cages: Option<Vec<OperatorCage>>,
+  #[tsify(optional)]
+  pub fortress_cells: Option<Vec<CellPosition>>,
 }
 
 #[derive(Debug, Clone)]
@@ -53,18 +105,44 @@ pub struct NormalizedSudokuConstraints {
   pub extra_regions: Vec<Region>,
   pub killer_cages: Vec<KillerCage>,
   pub thermos: Vec<Thermo>,
+  pub slow_thermos: Vec<Thermo>,
+  pub branching_thermos: Vec<BranchingThermo>,
   pub arrows: Vec<Arrow>,
   pub primary_diagonal: bool,
   pub secondary_diagonal: bool,
+  pub disjoint_groups: bool,
   pub anti_knight: bool,
   pub anti_king: bool,
+  pub non_consecutive: bool,
+  pub diagonal_non_consecutive: bool,
   pub kropki_dots: Vec<KropkiDot>,
   pub kropki_negative: bool,
+  pub xv_negative: bool,
   pub odd_cells: Vec<CellPosition>,
   pub even_cells: Vec<CellPosition>,
+  pub minimum_cells: Vec<CellPosition>,
+  pub maximum_cells: Vec<CellPosition>,
   pub top_bottom: bool,
   pub renbans: Vec<Renban>,
   pub palindromes: Vec<Palindrome>,
+  pub whispers: Vec<Whispers>,
+  pub difference_lines: Vec<DifferenceLine>,
+  pub between_lines: Vec<BetweenLine>,
+  pub region_sum_lines: Vec<RegionSumLine>,
+  pub entropic_lines: Vec<EntropicLine>,
+  pub parity_lines: Vec<ParityLine>,
+  pub zippers: Vec<Zipper>,
+  pub modular_lines: Vec<ModularLine>,
+  pub nabner_lines: Vec<NabnerLine>,
+  pub little_killers: Vec<LittleKiller>,
+  pub sandwiches: Vec<OutsideClue>,
+  pub x_sums: Vec<OutsideClue>,
+  pub skyscrapers: Vec<OutsideClue>,
+  pub quadruples: Vec<Quadruple>,
+  pub inequalities: Vec<Inequality>,
+  pub clones: Vec<CloneGroup>,
+  pub operator_cages: Vec<OperatorCage>,
+  pub fortress_cells: Vec<CellPosition>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Tsify)]
@@ -94,12 +172,18 @@ impl CellPosition {
   }
 }
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Tsify)]
 pub struct CellDirection {
   pub row: isize,
   pub col: isize,
 }
 
+impl CellDirection {
+  pub fn is_diagonal(&self) -> bool {
+    self.row.abs() == 1 && self.col.abs() == 1
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref, DerefMut)]
 #[tsify(from_wasm_abi)]
 pub struct Region(pub Vec<CellPosition>);
@@ -112,6 +196,13 @@ pub struct Grid(pub Vec<Vec<u32>>);
 #[tsify(from_wasm_abi)]
 pub struct KillerCage {
   pub sum: Option<u32>,
+  // The digits in the cage multiply to the product
+  #[tsify(optional)]
+  pub product: Option<u32>,
+  // Digits can repeat in cages that aren't unique (e.g. sum blobs)
+  #[tsify(optional)]
+  #[serde(default = "KillerCage::default_unique")]
+  pub unique: bool,
   pub region: Region,
 }
 
@@ -130,6 +221,9 @@ pub enum KropkiDotType {
   Consecutive,
   Double,
   Negative,
+  X,
+  V,
+  NegativeXV,
 }
 
 #[derive(Serialize, Deserialize, Debug, Tsify)]
@@ -220,9 +314,32 @@ pub enum Rule {
   RenbanCandidates,
   PalindromeValues,
   PalindromeCandidates,
+  WhispersCandidates,
+  DifferenceLineCandidates,
+  BetweenLineCandidates,
+  RegionSumLineCandidates,
+  EntropicLineCandidates,
+  ParityLineCandidates,
+  ZipperValues,
+  ZipperCandidates,
+  ModularLineCandidates,
+  NabnerLineCandidates,
+  LittleKillerCandidates,
+  SandwichCandidates,
+  XSumCandidates,
+  SkyscraperCandidates,
+  QuadrupleCandidates,
+  NonConsecutiveCandidates,
+  InequalityCandidates,
+  CloneValues,
+  CloneCandidates,
+  OperatorCageCandidates,
+  FortressCandidates,
+  MinMaxCandidates,
   // Medium
   ArrowAdvancedCandidates,
   Killer45,
+  Killer45Product,
   KropkiChainCandidates,
   KropkiAdvancedCandidates,
   TopBottomCandidates,
@@ -256,19 +373,48 @@ pub enum Area {
   Column(usize),
   Region(usize),
   Thermo(usize),
+  SlowThermo(usize),
+  BranchingThermo(usize),
   Arrow(usize),
   KillerCage(usize),
   KropkiDot(usize),
   PrimaryDiagonal,
   SecondaryDiagonal,
+  DisjointGroup(usize),
   Renban(usize),
   Palindrome(usize),
+  Whispers(usize),
+  DifferenceLine(usize),
+  BetweenLine(usize),
+  RegionSumLine(usize),
+  EntropicLine(usize),
+  ParityLine(usize),
+  Zipper(usize),
+  ModularLine(usize),
+  NabnerLine(usize),
+  LittleKiller(usize),
+  Sandwich(usize),
+  XSum(usize),
+  Skyscraper(usize),
+  Quadruple(usize),
+  Inequality(usize),
+  CloneGroup(usize),
+  OperatorCage(usize),
+  FortressCell(usize),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
 #[tsify(from_wasm_abi)]
 pub struct Thermo(pub Vec<CellPosition>);
 
+// A single bulb with several branches, each branch lists its cells after the bulb
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+pub struct BranchingThermo {
+  pub bulb: CellPosition,
+  pub branches: Vec<Vec<CellPosition>>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
 #[tsify(from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
@@ -285,6 +431,116 @@ pub struct Renban(pub Vec<CellPosition>);
 #[tsify(from_wasm_abi)]
 pub struct Palindrome(pub Vec<CellPosition>);
 
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct Whispers(pub Vec<CellPosition>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DifferenceLine {
+  pub cells: Vec<CellPosition>,
+  pub min_difference: u32,
+}
+
+// The first and last cells are the circles
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct BetweenLine(pub Vec<CellPosition>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct RegionSumLine(pub Vec<CellPosition>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct EntropicLine(pub Vec<CellPosition>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct ParityLine(pub Vec<CellPosition>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct Zipper(pub Vec<CellPosition>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct ModularLine(pub Vec<CellPosition>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct NabnerLine(pub Vec<CellPosition>);
+
+// The clue is outside the grid, so cell is the first cell on the diagonal
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+pub struct LittleKiller {
+  pub sum: u32,
+  pub cell: CellPosition,
+  pub direction: CellDirection,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Tsify)]
+#[tsify(from_wasm_abi)]
+pub enum OutsideCluePosition {
+  Top,
+  Bottom,
+  Left,
+  Right,
+}
+
+// A clue outside the grid for the row or column with the given index
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+pub struct OutsideClue {
+  pub value: u32,
+  pub position: OutsideCluePosition,
+  pub index: usize,
+}
+
+// The circle is at the bottom right corner of cell, so it touches 4 cells
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+pub struct Quadruple {
+  pub cell: CellPosition,
+  pub values: Vec<u32>,
+}
+
+// The sign between two adjacent cells points towards the smaller one
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct Inequality {
+  pub smaller_cell: CellPosition,
+  pub larger_cell: CellPosition,
+}
+
+// Same-shaped regions, each listed with its cells in corresponding order,
+// must contain the same digits in corresponding cells
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify, Deref)]
+#[tsify(from_wasm_abi)]
+pub struct CloneGroup(pub Vec<Vec<CellPosition>>);
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Tsify)]
+#[tsify(from_wasm_abi)]
+pub enum CageOperator {
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+}
+
+// Digits can repeat in the cage if they don't see each other.
+// Subtract and Divide are only valid for cages with 2 cells.
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[tsify(from_wasm_abi)]
+pub struct OperatorCage {
+  pub operator: CageOperator,
+  pub value: u32,
+  pub region: Region,
+}
+
 #[derive(Debug)]
 pub enum ConstraintError {
   InvalidValue {
@@ -300,6 +556,21 @@ impl TryFrom<SudokuConstraints> for NormalizedSudokuConstraints {
     if ![4, 6, 9].contains(&src.grid_size) {
       return Err(ConstraintError::InvalidValue { field: "grid_size", message: "Can only be 4, 6 or 9" })
     }
+    if src.little_killers.iter().flatten().any(|little_killer| !little_killer.direction.is_diagonal()) {
+      return Err(ConstraintError::InvalidValue { field: "little_killers", message: "Direction must be diagonal" })
+    }
+    if src.quadruples.iter().flatten().any(|quadruple| !quadruple.is_valid(src.grid_size)) {
+      return Err(ConstraintError::InvalidValue { field: "quadruples", message: "Must be inside the grid with at most 4 valid values" })
+    }
+    if src.clones.iter().flatten().any(|clone_group| !clone_group.is_valid()) {
+      return Err(ConstraintError::InvalidValue { field: "clones", message: "Clones in a group must have the same length" })
+    }
+    if src.operator_cages.iter().flatten().any(|operator_cage| !operator_cage.is_valid()) {
+      return Err(ConstraintError::InvalidValue { field: "operator_cages", message: "Subtract and Divide cages must have 2 cells" })
+    }
+    if src.skyscrapers.iter().flatten().any(|skyscraper| !(1..=src.grid_size as u32).contains(&skyscraper.value)) {
+      return Err(ConstraintError::InvalidValue { field: "skyscrapers", message: "Must be between 1 and the grid size" })
+    }
 
     Ok(NormalizedSudokuConstraints {
       grid_size: src.grid_size,
@@ -308,18 +579,44 @@ impl TryFrom<SudokuConstraints> for NormalizedSudokuConstraints {
       extra_regions: src.extra_regions.unwrap_or_default(),
       killer_cages: src.killer_cages.unwrap_or_default(),
       thermos: src.thermos.unwrap_or_default(),
+      slow_thermos: src.slow_thermos.unwrap_or_default(),
+      branching_thermos: src.branching_thermos.unwrap_or_default(),
       arrows: src.arrows.unwrap_or_default(),
       primary_diagonal: src.primary_diagonal.unwrap_or_default(),
       secondary_diagonal: src.secondary_diagonal.unwrap_or_default(),
+      disjoint_groups: src.disjoint_groups.unwrap_or_default(),
       anti_knight: src.anti_knight.unwrap_or_default(),
       anti_king: src.anti_king.unwrap_or_default(),
+      non_consecutive: src.non_consecutive.unwrap_or_default(),
+      diagonal_non_consecutive: src.diagonal_non_consecutive.unwrap_or_default(),
       kropki_dots: src.kropki_dots.unwrap_or_default(),
       kropki_negative: src.kropki_negative.unwrap_or_default(),
+      xv_negative: src.xv_negative.unwrap_or_default(),
       odd_cells: src.odd_cells.unwrap_or_default(),
       even_cells: src.even_cells.unwrap_or_default(),
+      minimum_cells: src.minimum_cells.unwrap_or_default(),
+      maximum_cells: src.maximum_cells.unwrap_or_default(),
       top_bottom: src.top_bottom.unwrap_or_default(),
       renbans: src.renbans.unwrap_or_default(),
       palindromes: src.palindromes.unwrap_or_default(),
+      whispers: src.whispers.unwrap_or_default(),
+      difference_lines: src.difference_lines.unwrap_or_default(),
+      between_lines: src.between_lines.unwrap_or_default(),
+      region_sum_lines: src.region_sum_lines.unwrap_or_default(),
+      entropic_lines: src.entropic_lines.unwrap_or_default(),
+      parity_lines: src.parity_lines.unwrap_or_default(),
+      zippers: src.zippers.unwrap_or_default(),
+      modular_lines: src.modular_lines.unwrap_or_default(),
+      nabner_lines: src.nabner_lines.unwrap_or_default(),
+      little_killers: src.little_killers.unwrap_or_default(),
+      sandwiches: src.sandwiches.unwrap_or_default(),
+      x_sums: src.x_sums.unwrap_or_default(),
+      skyscrapers: src.skyscrapers.unwrap_or_default(),
+      quadruples: src.quadruples.unwrap_or_default(),
+      inequalities: src.inequalities.unwrap_or_default(),
+      clones: src.clones.unwrap_or_default(),
+      operator_cages: src.operator_cages.unwrap_or_default(),
+      fortress_cells: src.fortress_cells.unwrap_or_default(),
     })
   }
 }
@@ -339,6 +636,173 @@ impl Arrow {
   }
 }
 
+impl Whispers {
+  // 5 for 9x9, 4 for 6x6 and 3 for 4x4, so the middle digits can never be on the line
+  pub fn min_difference(grid_size: usize) -> u32 {
+    grid_size as u32 / 2 + 1
+  }
+}
+
+impl Zipper {
+  // Pairs of positions equidistant from the centre of the line
+  pub fn pairs(&self) -> Vec<(usize, usize)> {
+    (0..self.len() / 2).map(|index| (index, self.len() - 1 - index)).collect()
+  }
+
+  pub fn center(&self) -> Option<usize> {
+    if self.len() % 2 == 1 { Some(self.len() / 2) } else { None }
+  }
+}
+
+impl KillerCage {
+  fn default_unique() -> bool {
+    true
+  }
+}
+
+// A regular cage with unique digits and no product
+impl Default for KillerCage {
+  fn default() -> KillerCage {
+    KillerCage {
+      sum: None,
+      product: None,
+      unique: KillerCage::default_unique(),
+      region: Region(vec![]),
+    }
+  }
+}
+
+impl LittleKiller {
+  pub fn cells(&self, grid_size: usize) -> Vec<CellPosition> {
+    let mut cells = vec![];
+    let mut row = self.cell.row as isize;
+    let mut col = self.cell.col as isize;
+    while row >= 0 && row < grid_size as isize && col >= 0 && col < grid_size as isize {
+      cells.push(CellPosition::new(row as usize, col as usize));
+      row += self.direction.row;
+      col += self.direction.col;
+    }
+    cells
+  }
+}
+
+impl OutsideClue {
+  pub fn new(value: u32, position: OutsideCluePosition, index: usize) -> OutsideClue {
+    OutsideClue { value, position, index }
+  }
+
+  // The cells of the row or column, ordered starting from the clue
+  pub fn cells(&self, grid_size: usize) -> Vec<CellPosition> {
+    (0..grid_size).map(|offset| {
+      match self.position {
+        OutsideCluePosition::Top => CellPosition::new(offset, self.index),
+        OutsideCluePosition::Bottom => CellPosition::new(grid_size - 1 - offset, self.index),
+        OutsideCluePosition::Left => CellPosition::new(self.index, offset),
+        OutsideCluePosition::Right => CellPosition::new(self.index, grid_size - 1 - offset),
+      }
+    }).collect()
+  }
+}
+
+impl Quadruple {
+  pub fn new(cell: CellPosition, values: Vec<u32>) -> Quadruple {
+    Quadruple { cell, values }
+  }
+
+  // The circle needs 4 cells around it and values that fit the grid
+  pub fn is_valid(&self, grid_size: usize) -> bool {
+    self.cell.row + 1 < grid_size && self.cell.col + 1 < grid_size && self.values.len() <= 4 &&
+      self.values.iter().all(|&value| 1 <= value && value as usize <= grid_size)
+  }
+
+  pub fn cells(&self) -> Vec<CellPosition> {
+    vec![
+      self.cell,
+      CellPosition::new(self.cell.row, self.cell.col + 1),
+      CellPosition::new(self.cell.row + 1, self.cell.col),
+      CellPosition::new(self.cell.row + 1, self.cell.col + 1),
+    ]
+  }
+}
+
+impl Inequality {
+  pub fn new(smaller_cell: CellPosition, larger_cell: CellPosition) -> Inequality {
+    Inequality { smaller_cell, larger_cell }
+  }
+}
+
+impl CloneGroup {
+  pub fn is_valid(&self) -> bool {
+    self.iter().map(|clone| clone.len()).all_equal()
+  }
+
+  // Cells with the same value, one from each clone
+  pub fn corresponding_cells(&self) -> Vec<Vec<CellPosition>> {
+    let clone_length = self.first().map(|clone| clone.len()).unwrap_or(0);
+    (0..clone_length).map(|position| {
+      self.iter().map(|clone| clone[position]).collect()
+    }).collect()
+  }
+}
+
+impl OperatorCage {
+  pub fn new(operator: CageOperator, value: u32, region: Region) -> OperatorCage {
+    OperatorCage { operator, value, region }
+  }
+
+  pub fn is_valid(&self) -> bool {
+    match self.operator {
+      CageOperator::Add | CageOperator::Multiply => true,
+      CageOperator::Subtract | CageOperator::Divide => self.region.len() == 2,
+    }
+  }
+
+  pub fn is_valid_for_values(&self, values: &[u32]) -> bool {
+    match self.operator {
+      CageOperator::Add => values.iter().sum::<u32>() == self.value,
+      CageOperator::Multiply => values.iter().product::<u32>() == self.value,
+      CageOperator::Subtract | CageOperator::Divide => {
+        let (low, high) = (values[0].min(values[1]), values[0].max(values[1]));
+        if self.operator == CageOperator::Subtract {
+          high - low == self.value
+        } else {
+          high == low * self.value
+        }
+      },
+    }
+  }
+}
+
+impl BranchingThermo {
+  pub fn new(bulb: CellPosition, branches: Vec<Vec<CellPosition>>) -> BranchingThermo {
+    BranchingThermo { bulb, branches }
+  }
+
+  // Each branch as a regular thermo that starts at the bulb
+  pub fn paths(&self) -> Vec<Thermo> {
+    self.branches.iter().map(|branch| {
+      Thermo([ vec![ self.bulb ], branch.clone() ].concat())
+    }).collect()
+  }
+
+  pub fn cells(&self) -> Vec<CellPosition> {
+    [ vec![ self.bulb ], self.branches.concat() ].concat()
+  }
+}
+
+impl EntropicLine {
+  // Low (0), middle (1) or high (2), e.g. 1-3, 4-6, 7-9 for 9x9 and 1-2, 3-4, 5-6 for 6x6
+  pub fn value_group(value: u32, grid_size: usize) -> u32 {
+    (value - 1) * 3 / grid_size as u32
+  }
+}
+
+impl ModularLine {
+  pub fn value_group(value: u32) -> u32 {
+    value % 3
+  }
+}
+
 impl SudokuConstraints {
   pub fn new(grid_size: usize) -> SudokuConstraints {
     SudokuConstraints {
@@ -348,18 +812,44 @@ impl SudokuConstraints {
       extra_regions: None,
       killer_cages: None,
       thermos: None,
+      slow_thermos: None,
+      branching_thermos: None,
       arrows: None,
       primary_diagonal: None,
       secondary_diagonal: None,
+      disjoint_groups: None,
       anti_knight: None,
       anti_king: None,
+      non_consecutive: None,
+      diagonal_non_consecutive: None,
       kropki_dots: None,
       kropki_negative: None,
+      xv_negative: None,
       odd_cells: None,
       even_cells: None,
+      minimum_cells: None,
+      maximum_cells: None,
       top_bottom: None,
       renbans: None,
       palindromes: None,
+      whispers: None,
+      difference_lines: None,
+      between_lines: None,
+      region_sum_lines: None,
+      entropic_lines: None,
+      parity_lines: None,
+      zippers: None,
+      modular_lines: None,
+      nabner_lines: None,
+      little_killers: None,
+      sandwiches: None,
+      x_sums: None,
+      skyscrapers: None,
+      quadruples: None,
+      inequalities: None,
+      clones: None,
+      operator_cages: None,
+      fortress_cells: None,
     }
   }
 
@@ -439,6 +929,12 @@ impl SudokuConstraints {
     self
   }
 
+  #[cfg(test)]
+  pub fn with_xv_negative(mut self) -> Self {
+    self.xv_negative = Some(true);
+    self
+  }
+
   #[cfg(test)]
   pub fn with_odd_cells(mut self, odd_cells: Vec<CellPosition>) -> Self {
     self.odd_cells = Some(odd_cells);
@@ -451,6 +947,18 @@ impl SudokuConstraints {
     self
   }
 
+  #[cfg(test)]
+  pub fn with_minimum_cells(mut self, minimum_cells: Vec<CellPosition>) -> Self {
+    self.minimum_cells = Some(minimum_cells);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_maximum_cells(mut self, maximum_cells: Vec<CellPosition>) -> Self {
+    self.maximum_cells = Some(maximum_cells);
+    self
+  }
+
   #[cfg(test)]
   pub fn with_palindromes(mut self, palindromes: Vec<Palindrome>) -> Self {
     self.palindromes = Some(palindromes);
@@ -463,6 +971,114 @@ impl SudokuConstraints {
     self
   }
 
+  #[cfg(test)]
+  pub fn with_whispers(mut self, whispers: Vec<Whispers>) -> Self {
+    self.whispers = Some(whispers);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_difference_lines(mut self, difference_lines: Vec<DifferenceLine>) -> Self {
+    self.difference_lines = Some(difference_lines);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_between_lines(mut self, between_lines: Vec<BetweenLine>) -> Self {
+    self.between_lines = Some(between_lines);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_region_sum_lines(mut self, region_sum_lines: Vec<RegionSumLine>) -> Self {
+    self.region_sum_lines = Some(region_sum_lines);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_entropic_lines(mut self, entropic_lines: Vec<EntropicLine>) -> Self {
+    self.entropic_lines = Some(entropic_lines);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_parity_lines(mut self, parity_lines: Vec<ParityLine>) -> Self {
+    self.parity_lines = Some(parity_lines);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_zippers(mut self, zippers: Vec<Zipper>) -> Self {
+    self.zippers = Some(zippers);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_modular_lines(mut self, modular_lines: Vec<ModularLine>) -> Self {
+    self.modular_lines = Some(modular_lines);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_nabner_lines(mut self, nabner_lines: Vec<NabnerLine>) -> Self {
+    self.nabner_lines = Some(nabner_lines);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_little_killers(mut self, little_killers: Vec<LittleKiller>) -> Self {
+    self.little_killers = Some(little_killers);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_sandwiches(mut self, sandwiches: Vec<OutsideClue>) -> Self {
+    self.sandwiches = Some(sandwiches);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_x_sums(mut self, x_sums: Vec<OutsideClue>) -> Self {
+    self.x_sums = Some(x_sums);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_skyscrapers(mut self, skyscrapers: Vec<OutsideClue>) -> Self {
+    self.skyscrapers = Some(skyscrapers);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_quadruples(mut self, quadruples: Vec<Quadruple>) -> Self {
+    self.quadruples = Some(quadruples);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_inequalities(mut self, inequalities: Vec<Inequality>) -> Self {
+    self.inequalities = Some(inequalities);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_clones(mut self, clones: Vec<CloneGroup>) -> Self {
+    self.clones = Some(clones);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_operator_cages(mut self, operator_cages: Vec<OperatorCage>) -> Self {
+    self.operator_cages = Some(operator_cages);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_fortress_cells(mut self, fortress_cells: Vec<CellPosition>) -> Self {
+    self.fortress_cells = Some(fortress_cells);
+    self
+  }
+
   #[cfg(test)]
   pub fn with_top_bottom(mut self) -> Self {
     self.top_bottom = Some(true);
@@ -481,6 +1097,18 @@ impl SudokuConstraints {
     self
   }
 
+  #[cfg(test)]
+  pub fn with_non_consecutive(mut self) -> Self {
+    self.non_consecutive = Some(true);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_diagonal_non_consecutive(mut self) -> Self {
+    self.diagonal_non_consecutive = Some(true);
+    self
+  }
+
   #[cfg(test)]
   pub fn with_primary_diagonal(mut self) -> Self {
     self.primary_diagonal = Some(true);
@@ -498,12 +1126,30 @@ impl SudokuConstraints {
     self.with_primary_diagonal().with_secondary_diagonal()
   }
 
+  #[cfg(test)]
+  pub fn with_disjoint_groups(mut self) -> Self {
+    self.disjoint_groups = Some(true);
+    self
+  }
+
   #[cfg(test)]
   pub fn with_thermos(mut self, thermos: Vec<Thermo>) -> Self {
     self.thermos = Some(thermos);
     self
   }
 
+  #[cfg(test)]
+  pub fn with_slow_thermos(mut self, slow_thermos: Vec<Thermo>) -> Self {
+    self.slow_thermos = Some(slow_thermos);
+    self
+  }
+
+  #[cfg(test)]
+  pub fn with_branching_thermos(mut self, branching_thermos: Vec<BranchingThermo>) -> Self {
+    self.branching_thermos = Some(branching_thermos);
+    self
+  }
+
   #[cfg(test)]
   pub fn with_arrows(mut self, arrows: Vec<Arrow>) -> Self {
     self.arrows = Some(arrows);
@@ -537,6 +1183,16 @@ impl FixedNumber {
   }
 }
 
+impl KropkiDotType {
+  // The dot type implied between adjacent cells that don't have a dot of this family
+  pub fn negative_dot_type(&self) -> KropkiDotType {
+    match self {
+      KropkiDotType::Consecutive | KropkiDotType::Double | KropkiDotType::Negative => KropkiDotType::Negative,
+      KropkiDotType::X | KropkiDotType::V | KropkiDotType::NegativeXV => KropkiDotType::NegativeXV,
+    }
+  }
+}
+
 impl KropkiDot {
   #[cfg(test)]
   pub fn consecutive(cell_1: CellPosition, cell_2: CellPosition) -> KropkiDot {
@@ -556,6 +1212,24 @@ impl KropkiDot {
     }
   }
 
+  #[cfg(test)]
+  pub fn x(cell_1: CellPosition, cell_2: CellPosition) -> KropkiDot {
+    KropkiDot {
+      dot_type: KropkiDotType::X,
+      cell_1,
+      cell_2,
+    }
+  }
+
+  #[cfg(test)]
+  pub fn v(cell_1: CellPosition, cell_2: CellPosition) -> KropkiDot {
+    KropkiDot {
+      dot_type: KropkiDotType::V,
+      cell_1,
+      cell_2,
+    }
+  }
+
   pub fn other_cell(&self, cell: &CellPosition) -> CellPosition {
     if self.cell_1.eq(cell) {
       self.cell_2
@@ -565,26 +1239,31 @@ impl KropkiDot {
   }
 
   pub fn check_values(&self, value1: u32, value2: u32) -> bool {
-    value1 == 0 ||
-      value2 == 0 ||
-      (
-        self.dot_type != KropkiDotType::Negative && (
-          self.apply_operation(value1) == value2 ||
-          self.apply_operation(value2) == value1
-        )
-      ) ||
-      (
-        self.dot_type == KropkiDotType::Negative &&
+    if value1 == 0 || value2 == 0 {
+      return true
+    }
+
+    match self.dot_type {
+      KropkiDotType::Consecutive | KropkiDotType::Double => {
+        self.apply_operation(value1) == Some(value2) || self.apply_operation(value2) == Some(value1)
+      },
+      KropkiDotType::Negative => {
         value1 + 1 != value2 && value2 + 1 != value1 &&
-        value1 * 2 != value2 && value2 * 2 != value1
-      )
+          value1 * 2 != value2 && value2 * 2 != value1
+      },
+      KropkiDotType::X => value1 + value2 == 10,
+      KropkiDotType::V => value1 + value2 == 5,
+      KropkiDotType::NegativeXV => value1 + value2 != 10 && value1 + value2 != 5,
+    }
   }
 
-  fn apply_operation(&self, value: u32) -> u32 {
+  // Only dots that relate a smaller and a larger value have an operation
+  fn apply_operation(&self, value: u32) -> Option<u32> {
     match self.dot_type {
-      KropkiDotType::Consecutive => value + 1,
-      KropkiDotType::Double => value * 2,
-      KropkiDotType::Negative => unimplemented!(),
+      KropkiDotType::Consecutive => Some(value + 1),
+      KropkiDotType::Double => Some(value * 2),
+      KropkiDotType::Negative | KropkiDotType::X | KropkiDotType::V |
+        KropkiDotType::NegativeXV => None,
     }
   }
 }
@@ -618,7 +1297,10 @@ impl SolutionStep {
   }
 
   pub fn is_grid_step(&self) -> bool {
-    [ Rule::NakedSingle, Rule::HiddenSingle, Rule::Thermo, Rule::PalindromeValues ].contains(&self.rule)
+    [
+      Rule::NakedSingle, Rule::HiddenSingle, Rule::Thermo, Rule::PalindromeValues, Rule::ZipperValues,
+      Rule::CloneValues,
+    ].contains(&self.rule)
   }
 }
 
@@ -678,10 +1360,16 @@ impl Area {
       Area::Column(col) => format!("column {}", col + 1),
       Area::Region(region) => format!("box {}", region + 1),
       Area::Grid | Area::Adhoc(_) | Area::Cell(_, _) |
-        Area::Thermo(_) | Area::Arrow(_) |
+        Area::Thermo(_) | Area::SlowThermo(_) | Area::BranchingThermo(_) | Area::Arrow(_) |
         Area::KillerCage(_) | Area::KropkiDot(_) |
-        Area::PrimaryDiagonal | Area::SecondaryDiagonal |
-        Area::Renban(_) | Area::Palindrome(_) => unimplemented!(),
+        Area::PrimaryDiagonal | Area::SecondaryDiagonal | Area::DisjointGroup(_) |
+        Area::Renban(_) | Area::Palindrome(_) | Area::Whispers(_) |
+        Area::DifferenceLine(_) | Area::BetweenLine(_) | Area::RegionSumLine(_) |
+        Area::EntropicLine(_) | Area::ParityLine(_) | Area::Zipper(_) |
+        Area::ModularLine(_) | Area::NabnerLine(_) | Area::LittleKiller(_) |
+        Area::Sandwich(_) | Area::XSum(_) | Area::Skyscraper(_) |
+        Area::Quadruple(_) | Area::Inequality(_) | Area::CloneGroup(_) |
+        Area::OperatorCage(_) | Area::FortressCell(_) => unimplemented!(),
     }
   }
 }